@@ -46,6 +46,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unnecessary_lazy_evaluations)]
     fn test_and_then() {
         let a = true.and_then(|| 1);
         let b = false.and_then(|| 0);
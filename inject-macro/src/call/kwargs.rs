@@ -2,8 +2,7 @@ use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::token::Brace;
-use syn::{braced, Expr, Ident, Result, Token};
+use syn::{braced, parse_quote, Expr, Ident, Result, Token};
 
 mod kw {
     syn::custom_keyword!(kwargs);
@@ -11,43 +10,44 @@ mod kw {
 
 pub struct Kwargs {
     pub keyword: kw::kwargs,
-    pub equals: Token![=],
-    pub brace: Brace,
     pub fields: Punctuated<Kwarg, Token![,]>,
 }
 
 impl Parse for Kwargs {
     fn parse(input: ParseStream) -> Result<Self> {
         let content;
+        let keyword = input.parse()?;
+        input.parse::<Token![=]>()?;
+        braced!(content in input);
         Ok(Self {
-            keyword: input.parse()?,
-            equals: input.parse()?,
-            brace: braced!(content in input),
+            keyword,
             fields: Punctuated::parse_terminated(&content)?,
         })
     }
 }
 
+/// A `member: expr` kwarg, or a `member` shorthand for `member: member`.
 pub struct Kwarg {
-    member: Ident,
-    colon: Token![:],
-    expr: Expr,
+    pub member: Ident,
+    pub expr: Expr,
 }
 
 impl Parse for Kwarg {
     fn parse(input: ParseStream) -> Result<Self> {
-        Ok(Self {
-            member: input.parse()?,
-            colon: input.parse()?,
-            expr: input.parse()?,
-        })
+        let member: Ident = input.parse()?;
+        let colon: Option<Token![:]> = input.parse()?;
+        let expr = match colon {
+            Some(_) => input.parse()?,
+            None => parse_quote!(#member),
+        };
+        Ok(Self { member, expr })
     }
 }
 
 impl ToTokens for Kwarg {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.member.to_tokens(tokens);
-        self.colon.to_tokens(tokens);
+        <Token![:]>::default().to_tokens(tokens);
         self.expr.to_tokens(tokens);
     }
 }
@@ -65,7 +65,7 @@ mod tests {
         };
 
         let kwargs: Kwargs = parse2(tree).unwrap();
-        let members = vec!["a", "b", "c"];
+        let members = ["a", "b", "c"];
 
         assert_eq!(kwargs.fields.len(), members.len());
 
@@ -74,6 +74,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parsing_shorthand() {
+        let tree = quote! {
+            kwargs = { a, b: 2 }
+        };
+
+        let kwargs: Kwargs = parse2(tree).unwrap();
+        let expanded: Vec<_> = kwargs
+            .fields
+            .iter()
+            .map(|kwarg| quote! { #kwarg }.to_string())
+            .collect();
+
+        assert_eq!(
+            expanded,
+            [quote! { a: a }.to_string(), quote! { b: 2 }.to_string()]
+        );
+    }
+
     #[test]
     fn test_to_tokens_for_kwarg() {
         let tree = quote! {
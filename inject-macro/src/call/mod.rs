@@ -1,47 +1,131 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
-use syn::{Expr, Ident, Result, Token};
+use syn::spanned::Spanned;
+use syn::{Error, Expr, ExprField, ExprPath, Ident, Member, Path, PathArguments, Result, Token};
 
 use crate::BoolToOption;
 
-mod kwargs;
+pub(crate) mod kwargs;
 
-use kwargs::Kwargs;
+use kwargs::{Kwarg, Kwargs};
 
 pub struct Call {
+    pub asyncness: Option<Token![async]>,
     pub ident: Expr,
-    pub comma1: Token![,],
-    pub func: Ident,
-    pub comma2: Option<Token![,]>,
+    pub func: Callee,
     pub kwargs: Option<Kwargs>,
 }
 
+/// The function called by `call!`, or a method together with its receiver.
+#[allow(clippy::large_enum_variant)]
+pub enum Callee {
+    Function(Path),
+    Method { receiver: Expr, method: Ident },
+}
+
 impl Call {
     pub fn expand(self) -> TokenStream {
         let Call {
+            asyncness,
             ident,
             func,
             kwargs,
             ..
         } = self;
-        let macro_name = format_ident!("__inject_{}", func);
-        let fields = kwargs.as_ref().map(|kwargs| {
-            let kwargs = kwargs.fields.iter();
-            quote! { #(, #kwargs )* }
-        });
+        let kwargs: Vec<_> = kwargs
+            .iter()
+            .flat_map(|kwargs| kwargs.fields.iter())
+            .collect();
+        let mutability = kwargs.is_empty().or(quote! { mut });
+
+        match func {
+            Callee::Function(func) => {
+                let helper = helper_path(func);
+                let kwargs = kwargs.iter().map(|Kwarg { member, expr, .. }| {
+                    quote! { __call.#member = ::std::option::Option::Some(#expr); }
+                });
+                let call = match asyncness {
+                    Some(_) => quote! { call_async },
+                    None => quote! { call },
+                };
+
+                quote! {
+                    {
+                        let #mutability __call = #helper::default();
+                        #(#kwargs)*
+                        __call.#call(#ident)
+                    }
+                }
+            }
+            Callee::Method { receiver, method } => {
+                let args = format_ident!("__inject_args_{}", method);
+                let kwargs = kwargs.iter().map(|Kwarg { member, expr, .. }| {
+                    let setter = format_ident!("__inject_set_{}__{}", method, member);
+                    quote! { __receiver.#setter(&mut __args, #expr); }
+                });
+                let call = match asyncness {
+                    Some(_) => format_ident!("__inject_await_{}", method),
+                    None => format_ident!("__inject_call_{}", method),
+                };
 
-        quote! { #macro_name ! (#ident #fields) }
+                quote! {
+                    {
+                        let __receiver = &(#receiver);
+                        let #mutability __args = __receiver.#args();
+                        #(#kwargs)*
+                        __receiver.#call(#ident, __args)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The path of the hidden struct generated by `#[inject]` for the function at `func`.
+fn helper_path(mut func: Path) -> Path {
+    if let Some(segment) = func.segments.last_mut() {
+        segment.ident = format_ident!("__inject_{}", segment.ident);
+        if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+            arguments.colon2_token = Some(Default::default());
+        }
+    }
+    func
+}
+
+impl Parse for Callee {
+    fn parse(input: ParseStream) -> Result<Self> {
+        match input.parse()? {
+            Expr::Path(ExprPath {
+                qself: None, path, ..
+            }) => Ok(Callee::Function(path)),
+            Expr::Field(ExprField {
+                base,
+                member: Member::Named(method),
+                ..
+            }) => Ok(Callee::Method {
+                receiver: *base,
+                method,
+            }),
+            expr => Err(Error::new(
+                expr.span(),
+                "expected a function, like 'module::function', or a method, like 'receiver.method'",
+            )),
+        }
     }
 }
 
 impl Parse for Call {
     fn parse(input: ParseStream) -> Result<Self> {
+        let asyncness = input.parse()?;
+        let ident = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let func = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
         Ok(Self {
-            ident: input.parse()?,
-            comma1: input.parse()?,
-            func: input.parse()?,
-            comma2: input.parse()?,
+            asyncness,
+            ident,
+            func,
             kwargs: (!input.is_empty()).and_then(|| input.parse()).transpose()?,
         })
     }
@@ -49,7 +133,7 @@ impl Parse for Call {
 
 #[cfg(test)]
 mod tests {
-    use super::Call;
+    use super::{Call, Callee};
     use quote::quote;
     use syn::parse2;
 
@@ -61,7 +145,7 @@ mod tests {
 
         let call: Call = parse2(tree).unwrap();
 
-        assert!(call.func == "a_func");
+        assert!(matches!(call.func, Callee::Function(path) if path.is_ident("a_func")));
     }
 
     #[test]
@@ -72,7 +156,18 @@ mod tests {
 
         let call: Call = parse2(tree).unwrap();
 
-        assert!(call.func == "a_func");
+        assert!(matches!(call.func, Callee::Function(path) if path.is_ident("a_func")));
+    }
+
+    #[test]
+    fn test_parsing_path() {
+        let tree = quote! {
+            &container, handlers::create_user
+        };
+
+        let call: Call = parse2(tree).unwrap();
+
+        assert!(matches!(call.func, Callee::Function(path) if path.segments.len() == 2));
     }
 
     #[test]
@@ -84,7 +179,10 @@ mod tests {
         let call = parse2::<Call>(tree).unwrap().expand();
 
         let expected = quote! {
-            __inject_a_func!(&container)
+            {
+                let __call = __inject_a_func::default();
+                __call.call(&container)
+            }
         };
 
         assert_eq!(call.to_string(), expected.to_string())
@@ -99,7 +197,67 @@ mod tests {
         let call = parse2::<Call>(tree).unwrap().expand();
 
         let expected = quote! {
-            __inject_a_func!(&container, a: 1)
+            {
+                let mut __call = __inject_a_func::default();
+                __call.a = ::std::option::Option::Some(1);
+                __call.call(&container)
+            }
+        };
+
+        assert_eq!(call.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_path_expansion() {
+        let tree = quote! {
+            &container, crate::handlers::create_user::<A, B>
+        };
+
+        let call = parse2::<Call>(tree).unwrap().expand();
+
+        let expected = quote! {
+            {
+                let __call = crate::handlers::__inject_create_user::<A, B>::default();
+                __call.call(&container)
+            }
+        };
+
+        assert_eq!(call.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_method_expansion() {
+        let tree = quote! {
+            &container, service.handle, kwargs = { req }
+        };
+
+        let call = parse2::<Call>(tree).unwrap().expand();
+
+        let expected = quote! {
+            {
+                let __receiver = &(service);
+                let mut __args = __receiver.__inject_args_handle();
+                __receiver.__inject_set_handle__req(&mut __args, req);
+                __receiver.__inject_call_handle(&container, __args)
+            }
+        };
+
+        assert_eq!(call.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_async_expansion() {
+        let tree = quote! {
+            async &container, a_func
+        };
+
+        let call = parse2::<Call>(tree).unwrap().expand();
+
+        let expected = quote! {
+            {
+                let __call = __inject_a_func::default();
+                __call.call_async(&container)
+            }
         };
 
         assert_eq!(call.to_string(), expected.to_string())
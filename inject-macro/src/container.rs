@@ -2,23 +2,71 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{Expr, Result, Token};
+use syn::{Expr, Ident, LitStr, Result, Token, Type};
+
+use crate::BoolToOption;
+
+mod kw {
+    syn::custom_keyword!(module);
+}
 
 pub struct Container {
-    providers: Punctuated<Provider, Token![,]>,
+    entries: Punctuated<Item, Token![,]>,
 }
 
 impl Container {
     pub fn expand(self) -> TokenStream {
-        let provider_calls = self.providers.into_iter().map(
-            |Provider {
-                 ref_token,
-                 provider,
-             }| match ref_token {
-                Some(_) => quote! { container.install_ref(#[allow(unused_variables)] #provider) },
-                None => quote! { container.install(#[allow(unused_variables)] #provider) },
+        let (overrides, entries): (Vec<_>, Vec<_>) = self
+            .entries
+            .into_iter()
+            .partition(|item| item.override_token.is_some());
+        let entries = entries
+            .into_iter()
+            .chain(overrides)
+            .map(|Item { entry, .. }| entry);
+        let provider_calls = entries.map(|entry| match entry {
+            Entry::Module(ModuleEntry { module, .. }) => quote! {
+                container.install_module(#module)
+            },
+            Entry::Binding(Binding {
+                trait_object,
+                implementation,
+                ..
+            }) => quote! {
+                container.bind_with::<#trait_object>(|container: &::inject::Container| {
+                    ::inject::get!(container, #implementation)
+                        .map(|instance| Box::new(instance) as Box<#trait_object>)
+                })
+            },
+            Entry::Provider(Provider {
+                async_token: Some(_),
+                name,
+                provider,
+                ..
+            }) => match name {
+                Some(Name { name, .. }) => {
+                    quote! { container.install_async_named(#name, #[allow(unused_variables)] #provider) }
+                }
+                None => quote! { container.install_async(#[allow(unused_variables)] #provider) },
+            },
+            Entry::Provider(Provider {
+                ref_token,
+                name,
+                provider,
+                ..
+            }) => match (ref_token, name) {
+                (Some(_), Some(Name { name, .. })) => {
+                    quote! { container.install_ref_named(#name, #[allow(unused_variables)] #provider) }
+                }
+                (None, Some(Name { name, .. })) => {
+                    quote! { container.install_named(#name, #[allow(unused_variables)] #provider) }
+                }
+                (Some(_), None) => {
+                    quote! { container.install_ref(#[allow(unused_variables)] #provider) }
+                }
+                (None, None) => quote! { container.install(#[allow(unused_variables)] #provider) },
             },
-        );
+        });
 
         quote! {
             {
@@ -34,21 +82,121 @@ impl Container {
 impl Parse for Container {
     fn parse(input: ParseStream) -> Result<Self> {
         Ok(Self {
-            providers: Punctuated::parse_terminated(&input)?,
+            entries: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// An entry, optionally prefixed by `override` to install it after every other entry, replacing
+/// the providers they install for the same type.
+struct Item {
+    override_token: Option<Token![override]>,
+    entry: Entry,
+}
+
+impl Parse for Item {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            override_token: input.parse()?,
+            entry: input.parse()?,
+        })
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+enum Entry {
+    Binding(Binding),
+    Module(ModuleEntry),
+    Provider(Provider),
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![dyn]) {
+            input.parse().map(Entry::Binding)
+        } else if ModuleEntry::peek(input) {
+            input.parse().map(Entry::Module)
+        } else {
+            input.parse().map(Entry::Provider)
+        }
+    }
+}
+
+/// A `module Module` entry, installing the providers of a module.
+struct ModuleEntry {
+    module: Expr,
+}
+
+impl ModuleEntry {
+    /// Whether the input starts with a `module` entry, like `module DbModule` or
+    /// `module |binder: &mut Container| ..`, rather than a provider expression using a variable
+    /// named `module`.
+    fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<kw::module>().is_ok()
+            && (fork.peek(Ident)
+                || fork.peek(Token![::])
+                || fork.peek(Token![|])
+                || fork.peek(Token![move]))
+    }
+}
+
+impl Parse for ModuleEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<kw::module>()?;
+        Ok(Self {
+            module: input.parse()?,
+        })
+    }
+}
+
+/// A `dyn Trait => Impl` entry, binding a trait object to an implementation.
+struct Binding {
+    trait_object: Type,
+    implementation: Type,
+}
+
+impl Parse for Binding {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let trait_object = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        Ok(Self {
+            trait_object,
+            implementation: input.parse()?,
         })
     }
 }
 
 struct Provider {
+    async_token: Option<Token![async]>,
     ref_token: Option<Token![ref]>,
+    name: Option<Name>,
     provider: Expr,
 }
 
 impl Parse for Provider {
     fn parse(input: ParseStream) -> Result<Self> {
+        let async_token: Option<Token![async]> = input.parse()?;
+        if async_token.is_some() && input.peek(Token![ref]) {
+            return Err(input.error("'ref' providers cannot be 'async'"));
+        }
         Ok(Self {
+            async_token,
             ref_token: input.parse()?,
+            name: input.peek(LitStr).and_then(|| input.parse()).transpose()?,
             provider: input.parse()?,
         })
     }
 }
+
+struct Name {
+    name: LitStr,
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        Ok(Self { name })
+    }
+}
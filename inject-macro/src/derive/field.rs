@@ -0,0 +1,102 @@
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Error, Expr, Result, Token};
+
+mod kw {
+    syn::custom_keyword!(default);
+    syn::custom_keyword!(named);
+    syn::custom_keyword!(no_inject);
+}
+
+/// The `#[inject(..)]` attributes of a field, equivalent to the arguments of `#[inject(..)]` for
+/// an argument of the same name.
+#[derive(Default)]
+pub struct FieldArgs {
+    pub default: Option<Expr>,
+    pub no_inject: bool,
+    pub named: Option<Expr>,
+}
+
+impl FieldArgs {
+    pub fn from_attributes(attributes: &[Attribute]) -> Result<Self> {
+        let mut args = Self::default();
+        let field_args = attributes
+            .iter()
+            .filter(|attribute| attribute.path.is_ident("inject"))
+            .map(|attribute| {
+                attribute.parse_args_with(Punctuated::<FieldArg, Token![,]>::parse_terminated)
+            });
+        for field_arg in field_args {
+            for field_arg in field_arg? {
+                args.add(field_arg)?;
+            }
+        }
+        Ok(args)
+    }
+
+    fn add(&mut self, arg: FieldArg) -> Result<()> {
+        match arg {
+            FieldArg::Default { keyword, value } => {
+                if self.default.is_some() {
+                    return Err(Error::new(keyword.span, "duplicate option 'default'"));
+                }
+                self.default =
+                    Some(value.unwrap_or_else(|| syn::parse_str("Default::default()").unwrap()));
+            }
+            FieldArg::NoInject { keyword } => {
+                if self.no_inject {
+                    return Err(Error::new(keyword.span, "duplicate option 'no_inject'"));
+                }
+                self.no_inject = true;
+            }
+            FieldArg::Named { keyword, name } => {
+                if self.named.is_some() {
+                    return Err(Error::new(keyword.span, "duplicate option 'named'"));
+                }
+                self.named = Some(name);
+            }
+        }
+        Ok(())
+    }
+}
+
+enum FieldArg {
+    Default {
+        keyword: kw::default,
+        value: Option<Expr>,
+    },
+    NoInject {
+        keyword: kw::no_inject,
+    },
+    Named {
+        keyword: kw::named,
+        name: Expr,
+    },
+}
+
+impl Parse for FieldArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        Ok(if lookahead.peek(kw::default) {
+            let keyword = input.parse()?;
+            let eq: Option<Token![=]> = input.parse()?;
+            Self::Default {
+                keyword,
+                value: eq.map(|_| input.parse()).transpose()?,
+            }
+        } else if lookahead.peek(kw::no_inject) {
+            Self::NoInject {
+                keyword: input.parse()?,
+            }
+        } else if lookahead.peek(kw::named) {
+            let keyword = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Self::Named {
+                keyword,
+                name: input.parse()?,
+            }
+        } else {
+            return Err(lookahead.error());
+        })
+    }
+}
@@ -0,0 +1,255 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_quote, Data, DeriveInput, Error, Fields, Generics, Ident, Result};
+
+use field::FieldArgs;
+
+use crate::inject::arguments::{is_type_param, Injection};
+
+mod field;
+
+/// A struct deriving `Inject`, with the injections of its fields.
+pub struct DeriveInject {
+    ident: Ident,
+    generics: Generics,
+    fields: Fields,
+    injections: Vec<Injection>,
+}
+
+impl DeriveInject {
+    pub fn expand(self) -> TokenStream {
+        let Self {
+            ident,
+            mut generics,
+            fields,
+            injections,
+        } = self;
+        let dependencies: Vec<_> = injections
+            .iter()
+            .map(|injection| injection.expand_dependency(&generics))
+            .collect();
+        for param in generics.type_params_mut() {
+            param.bounds.push(parse_quote!(::inject::Inject));
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let construct = |injections: Vec<TokenStream>| match &fields {
+            Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                quote! { Self { #(#names: #injections,)* } }
+            }
+            Fields::Unnamed(_) => quote! { Self( #(#injections,)* ) },
+            Fields::Unit => quote! { Self },
+        };
+        let construction = construct(injections.iter().map(Injection::expand).collect());
+        let async_construction =
+            construct(injections.iter().map(Injection::expand_async).collect());
+
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                pub fn inject(container: &::inject::Container) -> Result<Self, ::inject::InjectError> {
+                    Ok(#construction)
+                }
+
+                pub async fn inject_async(container: &::inject::Container) -> Result<Self, ::inject::InjectError> {
+                    Ok(#async_construction)
+                }
+
+                pub fn inject_dependencies() -> ::std::vec::Vec<::inject::graph::Dependency> {
+                    ::std::vec![ #(#dependencies),* ]
+                }
+            }
+        }
+    }
+}
+
+impl Parse for DeriveInject {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let DeriveInput {
+            ident,
+            generics,
+            data,
+            ..
+        } = input.parse()?;
+
+        let fields = match data {
+            Data::Struct(data) => data.fields,
+            _ => {
+                return Err(Error::new(
+                    ident.span(),
+                    "Inject can only be derived for structs",
+                ))
+            }
+        };
+
+        let injections = fields
+            .iter()
+            .map(|field| {
+                let FieldArgs {
+                    default,
+                    no_inject,
+                    named,
+                } = FieldArgs::from_attributes(&field.attrs)?;
+                // Type parameters can only be resolved using providers, as nothing is known
+                // about how to create them.
+                let should_inject = !no_inject && !is_type_param(&field.ty, &generics);
+                Ok(Injection::new(
+                    field.ty.clone(),
+                    should_inject,
+                    named,
+                    default,
+                ))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            ident,
+            generics,
+            fields,
+            injections,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::parse2;
+
+    use super::DeriveInject;
+
+    #[test]
+    fn test_expansion() {
+        let tree = quote! {
+            struct A {
+                b: B,
+                #[inject(default = 5, no_inject)]
+                c: isize,
+                #[inject(named = "primary")]
+                d: D,
+            }
+        };
+
+        let expected = quote! {
+            impl A {
+                pub fn inject(container: &::inject::Container) -> Result<Self, ::inject::InjectError> {
+                    Ok(Self {
+                        b: ::inject::get!(container, B, create: true)?,
+                        c: ::inject::get!(container, isize, create: false).or_else(|_| Ok(5))?,
+                        d: ::inject::get!(container, D, create: true, named: "primary")?,
+                    })
+                }
+
+                pub async fn inject_async(container: &::inject::Container) -> Result<Self, ::inject::InjectError> {
+                    Ok(Self {
+                        b: ::inject::get!(async container, B, create: true).await?,
+                        c: ::inject::get!(async container, isize, create: false).await.or_else(|_| Ok(5))?,
+                        d: ::inject::get!(async container, D, create: true, named: "primary").await?,
+                    })
+                }
+
+                pub fn inject_dependencies() -> ::std::vec::Vec<::inject::graph::Dependency> {
+                    ::std::vec![
+                        ::inject::graph::Dependency::of::<B>(::std::option::Option::None),
+                        ::inject::graph::Dependency::of::<isize>(::std::option::Option::None),
+                        ::inject::graph::Dependency::of::<D>(::std::option::Option::Some("primary"))
+                    ]
+                }
+            }
+        };
+
+        let derive = parse2::<DeriveInject>(tree).unwrap().expand();
+
+        assert_eq!(derive.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_tuple_struct_expansion() {
+        let tree = quote! {
+            struct A(B);
+        };
+
+        let expected = quote! {
+            impl A {
+                pub fn inject(container: &::inject::Container) -> Result<Self, ::inject::InjectError> {
+                    Ok(Self(::inject::get!(container, B, create: true)?,))
+                }
+
+                pub async fn inject_async(container: &::inject::Container) -> Result<Self, ::inject::InjectError> {
+                    Ok(Self(::inject::get!(async container, B, create: true).await?,))
+                }
+
+                pub fn inject_dependencies() -> ::std::vec::Vec<::inject::graph::Dependency> {
+                    ::std::vec![::inject::graph::Dependency::of::<B>(::std::option::Option::None)]
+                }
+            }
+        };
+
+        let derive = parse2::<DeriveInject>(tree).unwrap().expand();
+
+        assert_eq!(derive.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_generic_struct_expansion() {
+        let tree = quote! {
+            struct A<R> {
+                r: R,
+                b: Vec<R>,
+            }
+        };
+
+        let expected = quote! {
+            impl<R: ::inject::Inject> A<R> {
+                pub fn inject(container: &::inject::Container) -> Result<Self, ::inject::InjectError> {
+                    Ok(Self {
+                        r: ::inject::get!(container, R, create: false)?,
+                        b: ::inject::get!(container, Vec<R>, create: true)?,
+                    })
+                }
+
+                pub async fn inject_async(container: &::inject::Container) -> Result<Self, ::inject::InjectError> {
+                    Ok(Self {
+                        r: ::inject::get!(async container, R, create: false).await?,
+                        b: ::inject::get!(async container, Vec<R>, create: true).await?,
+                    })
+                }
+
+                pub fn inject_dependencies() -> ::std::vec::Vec<::inject::graph::Dependency> {
+                    ::std::vec![
+                        ::inject::graph::Dependency::new("R", ::std::option::Option::None),
+                        ::inject::graph::Dependency::new("Vec < R >", ::std::option::Option::None)
+                    ]
+                }
+            }
+        };
+
+        let derive = parse2::<DeriveInject>(tree).unwrap().expand();
+
+        assert_eq!(derive.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_parsing_duplicate_attribute_fails() {
+        let tree = quote! {
+            struct A {
+                #[inject(named = "a", named = "b")]
+                b: B,
+            }
+        };
+
+        assert!(parse2::<DeriveInject>(tree).is_err());
+    }
+
+    #[test]
+    fn test_parsing_enum_fails() {
+        let tree = quote! {
+            enum A {
+                B,
+            }
+        };
+
+        assert!(parse2::<DeriveInject>(tree).is_err());
+    }
+}
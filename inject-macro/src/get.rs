@@ -1,72 +1,115 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
-use syn::{Expr, LitBool, Path, Result, Token};
+use syn::spanned::Spanned;
+use syn::{
+    parse_quote, Error, Expr, GenericArgument, LitBool, Path, PathArguments, PathSegment, Result,
+    ReturnType, Token, TraitBound, Type, TypeGroup, TypeParamBound, TypeParen, TypePath,
+    TypeReference, TypeTraitObject,
+};
 
 use crate::bool_to_option::BoolToOption;
+use crate::call::kwargs::{Kwarg, Kwargs};
 
 mod kw {
     syn::custom_keyword!(create);
+    syn::custom_keyword!(named);
+    syn::custom_keyword!(kwargs);
 }
 
+#[derive(Clone)]
 pub struct Create {
-    pub keyword: kw::create,
-    pub colon: Token![:],
     pub boolean: LitBool,
 }
 
 impl Parse for Create {
     fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<kw::create>()?;
+        input.parse::<Token![:]>()?;
         Ok(Self {
-            keyword: input.parse()?,
-            colon: input.parse()?,
             boolean: input.parse()?,
         })
     }
 }
 
+#[derive(Clone)]
+pub struct Named {
+    pub name: Expr,
+}
+
+impl Parse for Named {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<kw::named>()?;
+        input.parse::<Token![:]>()?;
+        Ok(Self {
+            name: input.parse()?,
+        })
+    }
+}
+
 pub struct Get {
+    pub asyncness: Option<Token![async]>,
     pub expr: Expr,
-    pub comma: Token![,],
     pub ampersand: Option<Token![&]>,
-    pub ident: Path,
-    pub comma2: Option<Token![,]>,
+    pub ty: Type,
     pub create: Option<Create>,
+    pub named: Option<Named>,
+    pub kwargs: Option<Kwargs>,
 }
 
 impl Get {
     pub fn expand(self) -> TokenStream {
+        if let Some(kwargs) = &self.kwargs {
+            return self.expand_kwargs(kwargs);
+        }
+
+        if self.asyncness.is_some() {
+            return self.expand_async();
+        }
+
+        if let Some(optional) = self.expand_optional() {
+            return optional;
+        }
+
+        if let Some(deferred) = self.expand_deferred() {
+            return deferred;
+        }
+
+        let fallback = self.fallback();
         let Get {
-            ident,
+            ty,
             expr,
             ampersand,
-            create,
+            named,
             ..
         } = self;
-        let can_create = if let Some(create) = create {
-            create.boolean.value
-        } else {
-            true
-        };
 
-        let can_fallback = if let Some(segment) = ident.segments.last() {
-            (segment.ident != "Arc") && can_create
-        } else {
-            false
-        };
-
-        let fallback = can_fallback.and_then(|| {
-            quote! {.or_else(|_| <#ident>::inject(#expr))}
-        });
+        if let Some(Named { name, .. }) = named {
+            return if ampersand.is_none() {
+                quote! {
+                    (#expr).get_named::<#ty>(#name)
+                }
+            } else {
+                quote! {
+                    (#expr).get_ref_named::<#ty>(#name)
+                }
+            };
+        }
 
-        if ampersand.is_none() {
+        if ampersand.is_some() {
             quote! {
                 {
                     use ::inject::{Inject, InjectExt};
                     (#expr)
-                        .get::<#ident>()
-                        #fallback
-
+                        .get_ref::<#ty>()
+                }
+            }
+        } else if let Some(fallback) = fallback {
+            quote! {
+                {
+                    use ::inject::{Inject, InjectExt};
+                    (#expr)
+                        .get_or_else::<#ty>(|container| #fallback)
                 }
             }
         } else {
@@ -74,29 +117,398 @@ impl Get {
                 {
                     use ::inject::{Inject, InjectExt};
                     (#expr)
-                        .get_ref::<#ident>()
+                        .get::<#ty>()
+                }
+            }
+        }
+    }
+
+    /// Expands `get!(async ..)` into a future. References are resolved synchronously.
+    fn expand_async(self) -> TokenStream {
+        if let Some(optional) = self.expand_optional_async() {
+            return optional;
+        }
+
+        if let Some(deferred) = self.expand_deferred() {
+            return quote! {
+                ::std::future::ready(#deferred)
+            };
+        }
+
+        let fallback = self.fallback_async();
+        let Get {
+            ty,
+            expr,
+            ampersand,
+            named,
+            ..
+        } = self;
+
+        if let Some(Named { name, .. }) = named {
+            return if ampersand.is_none() {
+                quote! {
+                    (#expr).get_async_named::<#ty>(#name)
+                }
+            } else {
+                quote! {
+                    ::std::future::ready((#expr).get_ref_named::<#ty>(#name))
+                }
+            };
+        }
+
+        if ampersand.is_some() {
+            quote! {
+                ::std::future::ready((#expr).get_ref::<#ty>())
+            }
+        } else if let Some(fallback) = fallback {
+            quote! {
+                match #expr {
+                    __container => {
+                        use ::inject::{Inject, InjectExt};
+                        __container.get_async_or_else::<#ty>(#fallback)
+                    }
                 }
             }
+        } else {
+            quote! {
+                (#expr).get_async::<#ty>()
+            }
+        }
+    }
+
+    /// Creates the type when no provider is installed for it, using `container`, unless it can
+    /// only be resolved using providers.
+    fn fallback(&self) -> Option<TokenStream> {
+        let ty = &self.ty;
+        if let [element] = type_arguments(ty, "Vec")[..] {
+            Some(quote! {container.get_all::<#element>()})
+        } else if let [key, value] = type_arguments(ty, "HashMap")[..] {
+            Some(match factory_output(value) {
+                Some(output) => quote! {container.get_map_lazy::<#key, #output>()},
+                None => quote! {container.get_map::<#key, #value>()},
+            })
+        } else {
+            self.can_fallback().and_then(|| {
+                quote! {<#ty>::inject(container)}
+            })
+        }
+    }
+
+    /// Like [`Get::fallback`], as a future using `__container`, the evaluated `expr`.
+    fn fallback_async(&self) -> Option<TokenStream> {
+        let ty = &self.ty;
+        if let [element] = type_arguments(ty, "Vec")[..] {
+            Some(quote! {async move { __container.get_all::<#element>() }})
+        } else if let [key, value] = type_arguments(ty, "HashMap")[..] {
+            Some(match factory_output(value) {
+                Some(output) => quote! {async move { __container.get_map_lazy::<#key, #output>() }},
+                None => quote! {async move { __container.get_map::<#key, #value>() }},
+            })
+        } else {
+            self.can_fallback().and_then(|| {
+                quote! {<#ty>::inject_async(__container)}
+            })
+        }
+    }
+
+    /// The `get!` of the `T` of an `Option<T>`, which is created like `T` itself.
+    fn optional_inner(&self, inner: &Type) -> Get {
+        Get {
+            asyncness: self.asyncness,
+            expr: self.expr.clone(),
+            ampersand: None,
+            ty: inner.clone(),
+            create: self.create.clone(),
+            named: None,
+            kwargs: None,
+        }
+    }
+
+    /// Expands `get!` for an `Option<T>` or `Option<&T>`. `T` is created like `get!` does when no
+    /// provider is installed for it, and the option is `None` when that fails for lack of a
+    /// provider.
+    fn expand_optional(&self) -> Option<TokenStream> {
+        let Get {
+            expr, ty, named, ..
+        } = self;
+        let inner = self.optional_type()?;
+        let name = named.as_ref().map(|Named { name, .. }| name);
+
+        Some(match (inner, name) {
+            (Type::Reference(TypeReference { elem, .. }), Some(name)) => quote! {
+                (#expr).try_get_ref_named::<#elem>(#name)
+            },
+            (Type::Reference(TypeReference { elem, .. }), None) => quote! {
+                (#expr).try_get_ref::<#elem>()
+            },
+            (inner, Some(name)) => quote! {
+                (#expr).try_get_named::<#inner>(#name)
+            },
+            (inner, None) => match self.optional_inner(inner).fallback() {
+                Some(fallback) => quote! {
+                    {
+                        use ::inject::{Inject, InjectExt};
+                        (#expr).get_or_else::<#ty>(|container| {
+                            container.try_get_or_else::<#inner>(|container| #fallback)
+                        })
+                    }
+                },
+                None => quote! {
+                    (#expr).get_or_else::<#ty>(|container| container.try_get::<#inner>())
+                },
+            },
+        })
+    }
+
+    /// Expands `get!(async ..)` for an `Option<T>` or `Option<&T>` into a future.
+    fn expand_optional_async(&self) -> Option<TokenStream> {
+        let Get {
+            expr, ty, named, ..
+        } = self;
+        let inner = self.optional_type()?;
+        let name = named.as_ref().map(|Named { name, .. }| name);
+
+        Some(match (inner, name) {
+            (Type::Reference(_), _) => {
+                let optional = self.expand_optional()?;
+                quote! {
+                    ::std::future::ready(#optional)
+                }
+            }
+            (inner, Some(name)) => quote! {
+                (#expr).try_get_async_named::<#inner>(#name)
+            },
+            (inner, None) => match self.optional_inner(inner).fallback_async() {
+                Some(fallback) => quote! {
+                    match #expr {
+                        __container => {
+                            use ::inject::{Inject, InjectExt};
+                            __container.get_async_or_else::<#ty>(
+                                __container.try_get_async_or_else::<#inner>(#fallback)
+                            )
+                        }
+                    }
+                },
+                None => quote! {
+                    match #expr {
+                        __container => __container
+                            .get_async_or_else::<#ty>(__container.try_get_async::<#inner>()),
+                    }
+                },
+            },
+        })
+    }
+
+    /// Expands `get!` with `kwargs`, which constructs the type using its `#[inject]` constructor,
+    /// supplying the given arguments and resolving the others. Installed providers are bypassed.
+    fn expand_kwargs(&self, kwargs: &Kwargs) -> TokenStream {
+        let Get {
+            asyncness,
+            expr,
+            ty,
+            ..
+        } = self;
+        let mutability = kwargs.fields.is_empty().or(quote! { mut });
+        let setters = kwargs.fields.iter().map(|Kwarg { member, expr, .. }| {
+            let setter = format_ident!("__inject_set__{}", member);
+            quote! { <#ty>::#setter(&mut __args, #expr); }
+        });
+        let call = match asyncness {
+            Some(_) => quote! { __inject_await },
+            None => quote! { __inject_call },
+        };
+
+        quote! {
+            {
+                let #mutability __args = <#ty>::__inject_args();
+                #(#setters)*
+                <#ty>::#call(#expr, __args)
+            }
+        }
+    }
+
+    /// The `T` of an `Option<T>` being resolved as a value.
+    fn optional_type(&self) -> Option<&Type> {
+        if self.ampersand.is_some() {
+            return None;
+        }
+        match type_arguments(&self.ty, "Option")[..] {
+            [inner] => Some(inner),
+            _ => None,
         }
     }
+
+    /// Expands `get!` for a `Lazy<T>`, `Factory<T>` or `Box<dyn Fn() -> Result<T, _>>`, which is
+    /// created without a provider by deferring `get!` for `T` until it is used.
+    fn expand_deferred(&self) -> Option<TokenStream> {
+        if self.ampersand.is_some() {
+            return None;
+        }
+
+        let ty = &self.ty;
+        let (inner, deferred) = if let [inner] = type_arguments(ty, "Lazy")[..] {
+            (inner, quote! {::inject::Lazy::new(container, resolve)})
+        } else if let [inner] = type_arguments(ty, "Factory")[..] {
+            (inner, quote! {::inject::Factory::new(container, resolve)})
+        } else {
+            let inner = factory_output(ty)?;
+            let deferred = quote! {
+                {
+                    let factory = ::inject::Factory::new(container, resolve);
+                    Box::new(move || factory.create()) as #ty
+                }
+            };
+            (inner, deferred)
+        };
+
+        let resolve = Get {
+            asyncness: None,
+            expr: parse_quote!(container),
+            ampersand: None,
+            ty: inner.clone(),
+            create: self.create.clone(),
+            named: self.named.clone(),
+            kwargs: None,
+        }
+        .expand();
+        let expr = &self.expr;
+
+        Some(quote! {
+            {
+                use ::inject::{Inject, InjectExt};
+                (#expr)
+                    .get_or_else::<#ty>(|container| {
+                        let resolve = move |container: &::inject::Container| #resolve;
+                        Ok(#deferred)
+                    })
+            }
+        })
+    }
+
+    /// Whether the type may be created using its `inject` function, when no provider is installed.
+    fn can_fallback(&self) -> bool {
+        let can_create = if let Some(create) = &self.create {
+            create.boolean.value
+        } else {
+            true
+        };
+
+        let ty = &self.ty;
+        can_create
+            && !matches!(ty, Type::TraitObject(_))
+            && !matches!(type_arguments(ty, "Box")[..], [Type::TraitObject(_)])
+            && path_of(ty)
+                .and_then(|path| path.segments.last())
+                .is_none_or(|segment| segment.ident != "Arc")
+    }
+}
+
+/// The path of `ty`, if it is a path type.
+fn path_of(ty: &Type) -> Option<&Path> {
+    match ty {
+        Type::Path(TypePath { path, .. }) => Some(path),
+        Type::Group(TypeGroup { elem, .. }) | Type::Paren(TypeParen { elem, .. }) => path_of(elem),
+        _ => None,
+    }
+}
+
+/// The type arguments of `ty`, if it is a path type whose last segment is `ident`.
+pub fn type_arguments<'a>(ty: &'a Type, ident: &str) -> Vec<&'a Type> {
+    match path_of(ty).and_then(|path| path.segments.last()) {
+        Some(PathSegment {
+            ident: segment,
+            arguments: PathArguments::AngleBracketed(arguments),
+        }) if segment == ident => arguments
+            .args
+            .iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// The `T` of a `Box<dyn Fn() -> Result<T, _>>`.
+fn factory_output(ty: &Type) -> Option<&Type> {
+    let bounds = match type_arguments(ty, "Box")[..] {
+        [Type::TraitObject(TypeTraitObject { bounds, .. })] => bounds,
+        _ => return None,
+    };
+    let output = bounds.iter().find_map(|bound| match bound {
+        TypeParamBound::Trait(TraitBound { path, .. }) => match path.segments.last() {
+            Some(PathSegment {
+                ident,
+                arguments: PathArguments::Parenthesized(arguments),
+            }) if ident == "Fn" && arguments.inputs.is_empty() => match &arguments.output {
+                ReturnType::Type(_, output) => Some(output),
+                ReturnType::Default => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    })?;
+    type_arguments(output, "Result").first().copied()
 }
 
 impl Parse for Get {
     fn parse(input: ParseStream) -> Result<Self> {
+        let asyncness = input.parse()?;
         let expr = input.parse()?;
-        let comma = input.parse()?;
-        let ampersand = input.parse()?;
-        let ident: Path = input.parse()?;
-        let comma2 = input.parse()?;
-        let create = (!input.is_empty()).and_then(|| input.parse()).transpose()?;
+        input.parse::<Token![,]>()?;
+        let ampersand: Option<Token![&]> = input.parse()?;
+        let ty = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        let mut create: Option<Create> = None;
+        let mut named = None;
+        let mut kwargs: Option<Kwargs> = None;
+
+        while !input.is_empty() {
+            let lookahead = input.lookahead1();
+            if lookahead.peek(kw::create) {
+                if create.is_some() {
+                    return Err(input.error("duplicate option 'create'"));
+                }
+                create = Some(input.parse()?);
+            } else if lookahead.peek(kw::named) {
+                if named.is_some() {
+                    return Err(input.error("duplicate option 'named'"));
+                }
+                named = Some(input.parse()?);
+            } else if lookahead.peek(kw::kwargs) {
+                if kwargs.is_some() {
+                    return Err(input.error("duplicate option 'kwargs'"));
+                }
+                kwargs = Some(input.parse()?);
+            } else {
+                return Err(lookahead.error());
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        if let Some(kwargs) = &kwargs {
+            let constructs = ampersand.is_none()
+                && named.is_none()
+                && create.as_ref().is_none_or(|create| create.boolean.value);
+            if !constructs {
+                return Err(Error::new(
+                    kwargs.keyword.span(),
+                    "'kwargs' constructs the type, and cannot be used with '&', 'named' or 'create: false'",
+                ));
+            }
+        }
 
         Ok(Self {
+            asyncness,
             expr,
-            comma,
             ampersand,
-            ident,
-            comma2,
+            ty,
             create,
+            named,
+            kwargs,
         })
     }
 }
@@ -117,8 +529,7 @@ mod tests {
 
         let get: Get = parse2(tree).unwrap();
 
-        assert_eq!(get.ident.to_token_stream().to_string(), "A < isize >");
-        assert_eq!(get.comma.to_token_stream().to_string(), ",");
+        assert_eq!(get.ty.to_token_stream().to_string(), "A < isize >");
         assert_eq!(get.expr.to_token_stream().to_string(), "& container");
     }
 
@@ -132,8 +543,7 @@ mod tests {
             {
                 use ::inject::{Inject, InjectExt};
                 (&container)
-                    .get::<A<isize > >()
-                    .or_else(|_| < A < isize > >::inject(& container ) )
+                    .get_or_else::<A<isize > >(|container| < A < isize > >::inject(container))
             }
         };
 
@@ -159,4 +569,289 @@ mod tests {
 
         assert_eq!(get.to_string(), expected.to_string())
     }
+
+    #[test]
+    fn test_parsing_options_in_any_order() {
+        let tree = quote! {
+            &container, String, named: "db_url", create: false
+        };
+
+        let get: Get = parse2(tree).unwrap();
+
+        assert!(!get.create.unwrap().boolean.value);
+        assert_eq!(
+            get.named.unwrap().name.to_token_stream().to_string(),
+            "\"db_url\""
+        );
+    }
+
+    #[test]
+    fn test_parsing_duplicate_option_fails() {
+        let tree = quote! {
+            &container, String, named: "a", named: "b"
+        };
+
+        assert!(parse2::<Get>(tree).is_err());
+    }
+
+    #[test]
+    fn test_kwargs_expansion() {
+        let tree = quote! {
+            &container, Service, kwargs = { timeout: 5 }
+        };
+
+        let expected = quote! {
+            {
+                let mut __args = <Service>::__inject_args();
+                <Service>::__inject_set__timeout(&mut __args, 5);
+                <Service>::__inject_call(&container, __args)
+            }
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_parsing_kwargs_with_named_fails() {
+        let tree = quote! {
+            &container, Service, named: "a", kwargs = { timeout: 5 }
+        };
+
+        assert!(parse2::<Get>(tree).is_err());
+    }
+
+    #[test]
+    fn test_vec_expansion() {
+        let tree = quote! {
+            &container, Vec<A>
+        };
+
+        let expected = quote! {
+            {
+                use ::inject::{Inject, InjectExt};
+                (&container)
+                    .get_or_else::<Vec<A> >(|container| container.get_all::<A>())
+            }
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_map_expansion() {
+        let tree = quote! {
+            &container, HashMap<&str, A>
+        };
+
+        let expected = quote! {
+            {
+                use ::inject::{Inject, InjectExt};
+                (&container)
+                    .get_or_else::<HashMap<&str, A> >(|container| container.get_map::<&str, A>())
+            }
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_lazy_map_expansion() {
+        let tree = quote! {
+            &container, HashMap<Command, Box<dyn Fn() -> Result<A, InjectError>>>
+        };
+
+        let expected = quote! {
+            {
+                use ::inject::{Inject, InjectExt};
+                (&container)
+                    .get_or_else::<HashMap<Command, Box<dyn Fn() -> Result<A, InjectError> > > >(
+                        |container| container.get_map_lazy::<Command, A>()
+                    )
+            }
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_lazy_expansion() {
+        let tree = quote! {
+            &container, Lazy<A>, named: "primary"
+        };
+
+        let expected = quote! {
+            {
+                use ::inject::{Inject, InjectExt};
+                (&container)
+                    .get_or_else::<Lazy<A> >(|container| {
+                        let resolve = move |container: &::inject::Container|
+                            (container).get_named::<A>("primary");
+                        Ok(::inject::Lazy::new(container, resolve))
+                    })
+            }
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_optional_expansion() {
+        let tree = quote! {
+            &container, Option<A>
+        };
+
+        let expected = quote! {
+            {
+                use ::inject::{Inject, InjectExt};
+                (&container).get_or_else::<Option<A> >(|container| {
+                    container.try_get_or_else::<A>(|container| <A>::inject(container))
+                })
+            }
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_optional_without_creation_expansion() {
+        let tree = quote! {
+            &container, Option<A>, create: false
+        };
+
+        let expected = quote! {
+            (&container).get_or_else::<Option<A> >(|container| container.try_get::<A>())
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_optional_ref_expansion() {
+        let tree = quote! {
+            &container, Option<&Pool>, named: "replica"
+        };
+
+        let expected = quote! {
+            (&container).try_get_ref_named::<Pool>("replica")
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_named_expansion() {
+        let tree = quote! {
+            &container, String, named: "db_url"
+        };
+
+        let expected = quote! {
+            (&container).get_named::<String>("db_url")
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_named_ref_expansion() {
+        let tree = quote! {
+            &container, &Pool, named: "replica"
+        };
+
+        let expected = quote! {
+            (&container).get_ref_named::<Pool>("replica")
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_dyn_ref_expansion() {
+        let tree = quote! {
+            &container, &dyn Greeter
+        };
+
+        let expected = quote! {
+            {
+                use ::inject::{Inject, InjectExt};
+                (&container).get_ref::<dyn Greeter>()
+            }
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_boxed_dyn_expansion() {
+        let tree = quote! {
+            &container, Box<dyn Greeter>
+        };
+
+        let expected = quote! {
+            {
+                use ::inject::{Inject, InjectExt};
+                (&container).get::<Box<dyn Greeter> >()
+            }
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_async_expansion() {
+        let tree = quote! {
+            async &container, A
+        };
+
+        let expected = quote! {
+            match &container {
+                __container => {
+                    use ::inject::{Inject, InjectExt};
+                    __container.get_async_or_else::<A>(<A>::inject_async(__container))
+                }
+            }
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
+
+    #[test]
+    fn test_async_ref_expansion() {
+        let tree = quote! {
+            async &container, &A
+        };
+
+        let expected = quote! {
+            ::std::future::ready((&container).get_ref::<A>())
+        };
+
+        let get: TokenStream = parse2::<Get>(tree).unwrap().expand();
+
+        assert_eq!(get.to_string(), expected.to_string())
+    }
 }
@@ -49,14 +49,13 @@ impl Mergable for DefaultArgs {
         if let Some(same) = self.field_set().intersection(&other.field_set()).next() {
             return Err(duplicate_field_error(same));
         }
-        self.0.extend(other.0.into_iter());
+        self.0.extend(other.0);
         Ok(self)
     }
 }
 
 struct DefaultArg {
     pub field: Ident,
-    pub eq: Option<Token![=]>,
     pub value: Option<Expr>,
 }
 
@@ -69,6 +68,6 @@ impl Parse for DefaultArg {
         } else {
             None
         };
-        Ok(DefaultArg { field, eq, value })
+        Ok(DefaultArg { field, value })
     }
 }
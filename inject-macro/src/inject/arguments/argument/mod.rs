@@ -1,32 +1,28 @@
 use syn::parse::{Parse, ParseStream};
-use syn::token::Paren;
-use syn::{parenthesized, Result};
+use syn::{parenthesized, Ident, Result, Token};
 
 mod default;
 mod mergable;
+mod named;
 mod no_inject;
 
 pub use default::DefaultArgs;
 pub use mergable::Mergable;
+pub use named::NamedArgs;
 pub use no_inject::NoInjectArgs;
 
 mod kw {
     syn::custom_keyword!(default);
+    syn::custom_keyword!(named);
     syn::custom_keyword!(no_inject);
+    syn::custom_keyword!(post_construct);
 }
 
-#[allow(dead_code)]
 pub enum InjectArgument {
-    Default {
-        keyword: kw::default,
-        paren: Paren,
-        args: DefaultArgs,
-    },
-    NoInject {
-        keyword: kw::no_inject,
-        paren: Paren,
-        args: NoInjectArgs,
-    },
+    Default { args: DefaultArgs },
+    NoInject { args: NoInjectArgs },
+    Named { args: NamedArgs },
+    PostConstruct { method: Ident },
 }
 
 impl Parse for InjectArgument {
@@ -34,17 +30,29 @@ impl Parse for InjectArgument {
         let content;
         let lookahead = input.lookahead1();
         Ok(if lookahead.peek(kw::default) {
+            input.parse::<kw::default>()?;
+            parenthesized!(content in input);
             Self::Default {
-                keyword: input.parse()?,
-                paren: parenthesized!(content in input),
                 args: content.parse()?,
             }
         } else if lookahead.peek(kw::no_inject) {
+            input.parse::<kw::no_inject>()?;
+            parenthesized!(content in input);
             Self::NoInject {
-                keyword: input.parse()?,
-                paren: parenthesized!(content in input),
                 args: content.parse()?,
             }
+        } else if lookahead.peek(kw::named) {
+            input.parse::<kw::named>()?;
+            parenthesized!(content in input);
+            Self::Named {
+                args: content.parse()?,
+            }
+        } else if lookahead.peek(kw::post_construct) {
+            input.parse::<kw::post_construct>()?;
+            input.parse::<Token![=]>()?;
+            Self::PostConstruct {
+                method: input.parse()?,
+            }
         } else {
             return Err(lookahead.error());
         })
@@ -0,0 +1,64 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use syn::parse::Parse;
+use syn::punctuated::Punctuated;
+use syn::{Expr, Ident, Result, Token};
+
+use crate::inject::arguments::argument::mergable::Mergable;
+use crate::inject::arguments::error::duplicate_field_error;
+
+pub struct NamedArgs(HashMap<Ident, Expr>);
+
+impl NamedArgs {
+    pub fn remove(&mut self, field: &Ident) -> Option<Expr> {
+        self.0.remove(field)
+    }
+
+    pub fn field_set(&self) -> HashSet<Ident> {
+        self.fields().cloned().collect()
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &'_ Ident> {
+        self.0.keys()
+    }
+}
+
+impl Parse for NamedArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut arg_map = HashMap::default();
+        let args: Punctuated<NamedArg, Token![,]> = input.parse_terminated(NamedArg::parse)?;
+        for arg in args {
+            match arg_map.entry(arg.field.clone()) {
+                Entry::Vacant(entry) => entry.insert(arg.name),
+                _ => return Err(duplicate_field_error(&arg.field)),
+            };
+        }
+        Ok(NamedArgs(arg_map))
+    }
+}
+
+impl Mergable for NamedArgs {
+    fn merge(mut self, other: Self) -> Result<Self> {
+        if let Some(same) = self.field_set().intersection(&other.field_set()).next() {
+            return Err(duplicate_field_error(same));
+        }
+        self.0.extend(other.0);
+        Ok(self)
+    }
+}
+
+struct NamedArg {
+    pub field: Ident,
+    pub name: Expr,
+}
+
+impl Parse for NamedArg {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let field = input.parse()?;
+        input.parse::<Token![=]>()?;
+        Ok(NamedArg {
+            field,
+            name: input.parse()?,
+        })
+    }
+}
@@ -24,7 +24,7 @@ impl Mergable for NoInjectArgs {
         if let Some(same) = self.0.intersection(&other.0).next() {
             return Err(duplicate_field_error(same));
         }
-        self.0.extend(other.0.into_iter());
+        self.0.extend(other.0);
         Ok(self)
     }
 }
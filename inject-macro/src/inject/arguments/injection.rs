@@ -0,0 +1,116 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::visit_mut::{self, VisitMut};
+use syn::{parse_quote, Expr, Generics, Lifetime, Type, TypePath};
+
+use crate::get::type_arguments;
+
+/// The resolution of an argument or field from `container`.
+pub struct Injection {
+    ty: Type,
+    should_inject: bool,
+    named: Option<Expr>,
+    default_arg: Option<Expr>,
+}
+
+impl Injection {
+    pub fn new(
+        ty: Type,
+        should_inject: bool,
+        named: Option<Expr>,
+        default_arg: Option<Expr>,
+    ) -> Self {
+        Self {
+            ty,
+            should_inject,
+            named,
+            default_arg,
+        }
+    }
+
+    pub fn expand(&self) -> TokenStream {
+        self.expand_with(None, None)
+    }
+
+    /// Expands to an expression that must be within an async context.
+    pub fn expand_async(&self) -> TokenStream {
+        self.expand_with(Some(quote! { async }), Some(quote! { .await }))
+    }
+
+    /// Expands to the `::inject::graph::Dependency` resolved. Types mentioning a type parameter of
+    /// `generics`, which is not in scope, are named by their tokens.
+    pub fn expand_dependency(&self, generics: &Generics) -> TokenStream {
+        let named = match &self.named {
+            Some(name) => quote! { ::std::option::Option::Some(#name) },
+            None => quote! { ::std::option::Option::None },
+        };
+        if mentions_type_param(self.ty.to_token_stream(), generics) {
+            let type_name = self.ty.to_token_stream().to_string();
+            quote! { ::inject::graph::Dependency::new(#type_name, #named) }
+        } else {
+            let mut ty = self.ty.clone();
+            EraseLifetimes.visit_type_mut(&mut ty);
+            quote! { ::inject::graph::Dependency::of::<#ty>(#named) }
+        }
+    }
+
+    fn expand_with(
+        &self,
+        asyncness: Option<TokenStream>,
+        awaiting: Option<TokenStream>,
+    ) -> TokenStream {
+        let Self {
+            ty,
+            should_inject,
+            named,
+            default_arg,
+        } = self;
+        let named = named.as_ref().map(|name| quote! { , named: #name });
+        let injection = quote! {
+            ::inject::get!(#asyncness container, #ty, create: #should_inject #named)#awaiting
+        };
+        let injection = if default_arg.is_some() {
+            quote! { #injection.or_else(|_| Ok(#default_arg) ) }
+        } else {
+            injection
+        };
+        quote! { #injection? }
+    }
+}
+
+/// Whether `ty` is one of the type parameters of `generics`, or an `Option` of one.
+pub fn is_type_param(ty: &Type, generics: &Generics) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => match path.get_ident() {
+            Some(ident) => generics.type_params().any(|param| &param.ident == ident),
+            None => matches!(
+                type_arguments(ty, "Option")[..],
+                [inner] if is_type_param(inner, generics)
+            ),
+        },
+        Type::Group(group) => is_type_param(&group.elem, generics),
+        Type::Paren(paren) => is_type_param(&paren.elem, generics),
+        _ => false,
+    }
+}
+
+fn mentions_type_param(tokens: TokenStream, generics: &Generics) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => generics.type_params().any(|param| param.ident == ident),
+        TokenTree::Group(group) => mentions_type_param(group.stream(), generics),
+        _ => false,
+    })
+}
+
+/// Replaces the lifetimes of a type by `'_`, except for `'static`, so that it can be named
+/// outside of the function declaring them.
+struct EraseLifetimes;
+
+impl VisitMut for EraseLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident != "static" {
+            *lifetime = parse_quote!('_);
+        }
+        visit_mut::visit_lifetime_mut(self, lifetime);
+    }
+}
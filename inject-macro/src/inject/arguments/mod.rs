@@ -1,8 +1,6 @@
 use std::collections::HashSet;
 
-use argument::{DefaultArgs, InjectArgument, Mergable, NoInjectArgs};
-use proc_macro2::TokenStream;
-use quote::quote;
+use argument::{DefaultArgs, InjectArgument, Mergable, NamedArgs, NoInjectArgs};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
@@ -12,45 +10,58 @@ use crate::inject::input::InjectableSignature;
 
 mod argument;
 pub mod error;
+mod injection;
+
+pub use injection::{is_type_param, Injection};
+
+const RECEIVER_ERROR: &str = "only '&self' receivers are supported";
 
 pub(crate) struct InjectArgs {
     default_args: Option<DefaultArgs>,
     no_inject_args: Option<NoInjectArgs>,
+    named_args: Option<NamedArgs>,
+    post_construct: Option<Ident>,
 }
 
 impl InjectArgs {
-    pub fn expand_signature(
-        mut self,
-        sig: &dyn InjectableSignature,
-    ) -> Result<Vec<Option<TokenStream>>> {
+    /// The method called on a constructed instance, given by `post_construct = method`.
+    pub fn take_post_construct(&mut self) -> Option<Ident> {
+        self.post_construct.take()
+    }
+
+    pub fn expand_signature(mut self, sig: &dyn InjectableSignature) -> Result<Vec<Injection>> {
         let mut args = vec![];
         let mut fields = vec![];
         for argument in sig.inputs() {
             match argument {
                 syn::FnArg::Typed(syn::PatType { pat, ty, .. }) => {
                     if let syn::Pat::Ident(syn::PatIdent { ident, .. }) = pat.as_ref() {
-                        let default_arg = self.get_default(&ident);
-                        let should_inject = !self.is_no_inject(&ident);
+                        if ident == "self" {
+                            return Err(Error::new(ident.span(), RECEIVER_ERROR));
+                        }
+                        let default_arg = self.get_default(ident);
+                        // Type parameters can only be resolved using providers, as nothing is
+                        // known about how to create them.
+                        let should_inject =
+                            !self.is_no_inject(ident) && !is_type_param(ty, sig.generics());
+                        let named = self.get_named(ident);
                         fields.push(format!("'{}'", ident));
-                        let injected_arg =
-                            Some(quote! { ::inject::get!(container, #ty, create: #should_inject) })
-                                .map(|injection| {
-                                    if default_arg.is_some() {
-                                        quote! { #injection.or_else(|_| Ok(#default_arg) ) }
-                                    } else {
-                                        injection
-                                    }
-                                })
-                                .map(|injection| quote! { #injection? });
-
-                        args.push(injected_arg)
+
+                        args.push(Injection::new(
+                            ty.as_ref().clone(),
+                            should_inject,
+                            named,
+                            default_arg,
+                        ))
                     }
                 }
+                syn::FnArg::Receiver(syn::Receiver {
+                    reference: Some(_),
+                    mutability: None,
+                    ..
+                }) => {}
                 syn::FnArg::Receiver(receiver) => {
-                    return Err(Error::new(
-                        receiver.self_token.span(),
-                        "not allowed to reference 'self'",
-                    ))
+                    return Err(Error::new(receiver.self_token.span(), RECEIVER_ERROR))
                 }
             }
         }
@@ -68,19 +79,22 @@ impl InjectArgs {
     fn get_default(&mut self, field: &Ident) -> Option<Expr> {
         self.default_args
             .as_mut()
-            .map(|args| args.remove(&field))
-            .flatten()
+            .and_then(|args| args.remove(field))
+    }
+
+    fn get_named(&mut self, field: &Ident) -> Option<Expr> {
+        self.named_args.as_mut().and_then(|args| args.remove(field))
     }
 
     fn is_no_inject(&mut self, field: &Ident) -> bool {
         self.no_inject_args
             .as_mut()
-            .map(|args| args.remove(&field))
+            .map(|args| args.remove(field))
             .unwrap_or(false)
     }
 
     fn remaining(&self) -> HashSet<&Ident> {
-        &self.remaining_defaults() | &self.remaining_no_injects()
+        &(&self.remaining_defaults() | &self.remaining_no_injects()) | &self.remaining_named()
     }
 
     fn remaining_defaults(&self) -> HashSet<&Ident> {
@@ -96,29 +110,48 @@ impl InjectArgs {
             .map(|args| args.fields().collect())
             .unwrap_or_default()
     }
+
+    fn remaining_named(&self) -> HashSet<&Ident> {
+        self.named_args
+            .as_ref()
+            .map(|args| args.fields().collect())
+            .unwrap_or_default()
+    }
 }
 
 impl Parse for InjectArgs {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut default_args = vec![];
         let mut no_inject_args = vec![];
+        let mut named_args = vec![];
+        let mut post_construct = None;
 
         let parsed_arguments: Punctuated<InjectArgument, Token![,]> =
             input.parse_terminated(InjectArgument::parse)?;
 
         for arg in parsed_arguments {
             match arg {
-                InjectArgument::Default { args, .. } => default_args.push(args),
-                InjectArgument::NoInject { args, .. } => no_inject_args.push(args),
+                InjectArgument::Default { args } => default_args.push(args),
+                InjectArgument::NoInject { args } => no_inject_args.push(args),
+                InjectArgument::Named { args } => named_args.push(args),
+                InjectArgument::PostConstruct { method } => {
+                    if post_construct.is_some() {
+                        return Err(Error::new(method.span(), "duplicate 'post_construct'"));
+                    }
+                    post_construct = Some(method);
+                }
             }
         }
 
         let default_args = Mergable::merge_many(default_args)?;
         let no_inject_args = Mergable::merge_many(no_inject_args)?;
+        let named_args = Mergable::merge_many(named_args)?;
 
         Ok(InjectArgs {
             default_args,
             no_inject_args,
+            named_args,
+            post_construct,
         })
     }
 }
@@ -0,0 +1,110 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    parse_quote, GenericParam, Generics, Ident, Lifetime, ParenthesizedGenericArguments,
+    ReturnType, Type, TypeBareFn, TypeReference,
+};
+
+use crate::inject::input::InjectableSignature;
+use crate::BoolToOption;
+
+/// An injectable function or method, as seen by the hidden items calling it with some arguments
+/// supplied explicitly. Elided lifetimes are named `'__inject`, the lifetime of the container the
+/// remaining arguments are resolved from.
+pub struct Callable<'a> {
+    pub inputs: Vec<&'a Ident>,
+    pub input_types: Vec<Type>,
+    pub output: Type,
+    /// The generics of the function, prepended by `'__inject`.
+    pub generics: Generics,
+    pub turbofish: Option<TokenStream>,
+    /// A `PhantomData` using every lifetime and type parameter of `generics`.
+    pub marker: TokenStream,
+}
+
+impl<'a> Callable<'a> {
+    pub fn new(sig: &'a dyn InjectableSignature, output: &ReturnType) -> Self {
+        let (inputs, input_types) = sig
+            .typed_inputs()
+            .into_iter()
+            .map(|(ident, ty)| (ident, named_lifetimes(ty)))
+            .unzip();
+        let output = match output {
+            ReturnType::Default => parse_quote!(()),
+            ReturnType::Type(_, ty) => named_lifetimes(ty),
+        };
+
+        let mut generics = sig.generics().clone();
+        for param in generics.type_params_mut() {
+            param.bounds.push(parse_quote!(::inject::Inject));
+        }
+        let turbofish_args: Vec<_> = generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => Some(&param.ident),
+                GenericParam::Const(param) => Some(&param.ident),
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect();
+        let turbofish =
+            (!turbofish_args.is_empty()).and_then(|| quote! { ::<#(#turbofish_args),*> });
+        let lifetimes = generics.lifetimes().map(|param| &param.lifetime);
+        let type_params = generics.type_params().map(|param| &param.ident);
+        let marker = quote! {
+            ::std::marker::PhantomData<(&'__inject (), #(&#lifetimes (),)* #(fn() -> #type_params,)*)>
+        };
+        generics.params.insert(0, parse_quote!('__inject));
+
+        Self {
+            inputs,
+            input_types,
+            output,
+            generics,
+            turbofish,
+            marker,
+        }
+    }
+
+    /// The arguments to call the function with, each taken from `supplied` if it is `Some`, and
+    /// resolved using `injections` otherwise.
+    pub fn arguments(&self, supplied: &[TokenStream], injections: &[TokenStream]) -> TokenStream {
+        quote! {
+            #( match #supplied {
+                ::std::option::Option::Some(arg) => arg,
+                ::std::option::Option::None => #injections,
+            }, )*
+        }
+    }
+}
+
+fn named_lifetimes(ty: &Type) -> Type {
+    let mut ty = ty.clone();
+    NameElidedLifetimes.visit_type_mut(&mut ty);
+    ty
+}
+
+/// Names the lifetimes elided in a type `'__inject`. Lifetimes elided in function types are left
+/// alone, as they are not bound by the container.
+struct NameElidedLifetimes;
+
+impl VisitMut for NameElidedLifetimes {
+    fn visit_type_reference_mut(&mut self, reference: &mut TypeReference) {
+        if reference.lifetime.is_none() {
+            reference.lifetime = Some(parse_quote!('__inject));
+        }
+        visit_mut::visit_type_reference_mut(self, reference);
+    }
+
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if lifetime.ident == "_" {
+            *lifetime = parse_quote!('__inject);
+        }
+    }
+
+    fn visit_type_bare_fn_mut(&mut self, _: &mut TypeBareFn) {}
+
+    fn visit_parenthesized_generic_arguments_mut(&mut self, _: &mut ParenthesizedGenericArguments) {
+    }
+}
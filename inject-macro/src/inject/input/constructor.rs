@@ -1,35 +1,26 @@
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::token::Paren;
 use syn::{parenthesized, Attribute, Block, FnArg, Generics, Ident, Result, Token, Visibility};
 
 use crate::inject::input::InjectableSignature;
 
 pub struct ConstructorImpl {
-    pub attrs: Vec<Attribute>,
     pub vis: Visibility,
-    pub defaultness: Option<Token![default]>,
     pub sig: Constructor,
-    pub block: Block,
 }
 
 impl Parse for ConstructorImpl {
     fn parse(input: ParseStream) -> Result<Self> {
-        Ok(Self {
-            attrs: Attribute::parse_outer(input)?,
-            vis: input.parse()?,
-            defaultness: input.parse()?,
-            sig: input.parse()?,
-            block: input.parse()?,
-        })
+        Attribute::parse_outer(input)?;
+        let vis = input.parse()?;
+        input.parse::<Option<Token![default]>>()?;
+        let sig = input.parse()?;
+        input.parse::<Block>()?;
+        Ok(Self { vis, sig })
     }
 }
 
 impl InjectableSignature for Constructor {
-    fn ident(&self) -> &Ident {
-        &self.ident
-    }
-
     fn generics(&self) -> &Generics {
         &self.generics
     }
@@ -40,28 +31,31 @@ impl InjectableSignature for Constructor {
 }
 
 pub struct Constructor {
-    pub fn_token: Token![fn],
+    pub asyncness: Option<Token![async]>,
     pub ident: Ident,
     pub generics: Generics,
-    pub paren: Paren,
     pub inputs: Punctuated<FnArg, Token![,]>,
-    pub arrow: Token![->],
-    pub output: Token![Self],
 }
 
 impl Parse for Constructor {
     fn parse(input: ParseStream) -> Result<Self> {
         let content;
+        let asyncness = input.parse()?;
+        input.parse::<Token![fn]>()?;
+        let ident = input.parse()?;
+        let mut generics: Generics = input.parse()?;
+        parenthesized!(content in input);
+        let inputs = Punctuated::parse_terminated(&content)?;
+        input.parse::<Token![->]>()?;
+        input
+            .parse::<Token![Self]>()
+            .map_err(|_| input.error("expected 'Self'"))?;
+        generics.where_clause = input.parse()?;
         Ok(Self {
-            fn_token: input.parse()?,
-            ident: input.parse()?,
-            generics: input.parse()?,
-            paren: parenthesized!(content in input),
-            inputs: Punctuated::parse_terminated(&content)?,
-            arrow: input.parse()?,
-            output: input
-                .parse()
-                .or_else(|_| Err(input.error("expected 'Self'")))?,
+            asyncness,
+            ident,
+            generics,
+            inputs,
         })
     }
 }
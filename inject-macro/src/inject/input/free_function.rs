@@ -1,6 +1,5 @@
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::token::Paren;
 use syn::{
     parenthesized, Attribute, Block, FnArg, Generics, Ident, Result, ReturnType, Token, Type,
     TypePath, Visibility,
@@ -9,30 +8,25 @@ use syn::{
 use crate::inject::input::InjectableSignature;
 
 pub struct FreeFunctionImpl {
-    pub attrs: Vec<Attribute>,
     pub vis: Visibility,
     pub sig: FreeFunction,
-    pub block: Block,
 }
 
 impl Parse for FreeFunctionImpl {
     fn parse(input: ParseStream) -> Result<Self> {
-        Ok(Self {
-            attrs: Attribute::parse_outer(input)?,
-            vis: input.parse()?,
-            sig: input.parse()?,
-            block: input.parse()?,
-        })
+        Attribute::parse_outer(input)?;
+        let vis = input.parse()?;
+        let sig = input.parse()?;
+        input.parse::<Block>()?;
+        Ok(Self { vis, sig })
     }
 }
 
 pub struct FreeFunction {
     pub unsafety: Option<Token![unsafe]>,
     pub asyncness: Option<Token![async]>,
-    pub fn_token: Token![fn],
     pub ident: Ident,
     pub generics: Generics,
-    pub paren: Paren,
     pub inputs: Punctuated<FnArg, Token![,]>,
     pub output: ReturnType,
 }
@@ -42,12 +36,13 @@ impl Parse for FreeFunction {
         let content;
         let unsafety = input.parse()?;
         let asyncness = input.parse()?;
-        let fn_token = input.parse()?;
+        input.parse::<Token![fn]>()?;
         let ident = input.parse()?;
-        let generics = input.parse()?;
-        let paren = parenthesized!(content in input);
+        let mut generics: Generics = input.parse()?;
+        parenthesized!(content in input);
         let inputs = Punctuated::parse_terminated(&content)?;
         let output = input.parse()?;
+        generics.where_clause = input.parse()?;
         if let ReturnType::Type(_, ty) = &output {
             if let Type::Path(TypePath { path, .. }) = ty.as_ref() {
                 if let Some(segment) = path.segments.last() {
@@ -60,10 +55,8 @@ impl Parse for FreeFunction {
         Ok(Self {
             unsafety,
             asyncness,
-            fn_token,
             ident,
             generics,
-            paren,
             inputs,
             output,
         })
@@ -71,10 +64,6 @@ impl Parse for FreeFunction {
 }
 
 impl InjectableSignature for FreeFunction {
-    fn ident(&self) -> &Ident {
-        &self.ident
-    }
-
     fn generics(&self) -> &Generics {
         &self.generics
     }
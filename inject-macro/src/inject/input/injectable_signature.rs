@@ -1,22 +1,21 @@
 use syn::punctuated::Punctuated;
-use syn::{FnArg, Generics, Ident, Token};
+use syn::{FnArg, Generics, Ident, Token, Type};
 
 pub trait InjectableSignature {
-    fn ident(&self) -> &Ident;
-
     fn generics(&self) -> &Generics;
 
     fn inputs(&self) -> &Punctuated<FnArg, Token![,]>;
 
-    fn input_idents(&self) -> Vec<&Ident> {
+    /// The arguments bound to an identifier, together with their types.
+    fn typed_inputs(&self) -> Vec<(&Ident, &Type)> {
         self.inputs()
             .iter()
             .filter_map(|arg| match arg {
-                syn::FnArg::Typed(syn::PatType { pat, .. }) => Some(pat),
+                syn::FnArg::Typed(syn::PatType { pat, ty, .. }) => Some((pat, ty)),
                 _ => None,
             })
-            .filter_map(|pat| match pat.as_ref() {
-                syn::Pat::Ident(syn::PatIdent { ident, .. }) => Some(ident),
+            .filter_map(|(pat, ty)| match pat.as_ref() {
+                syn::Pat::Ident(syn::PatIdent { ident, .. }) => Some((ident, ty.as_ref())),
                 _ => None,
             })
             .collect()
@@ -24,10 +23,6 @@ pub trait InjectableSignature {
 }
 
 impl InjectableSignature for syn::Signature {
-    fn ident(&self) -> &Ident {
-        &self.ident
-    }
-
     fn generics(&self) -> &Generics {
         &self.generics
     }
@@ -1,39 +1,36 @@
 use syn::parse::{Parse, ParseStream};
-use syn::{Ident, Result};
+use syn::{ImplItemMethod, Result};
 
 mod constructor;
 mod free_function;
 mod injectable_signature;
 
-use constructor::ConstructorImpl;
-use free_function::FreeFunctionImpl;
+pub use constructor::ConstructorImpl;
+pub use free_function::FreeFunctionImpl;
 pub use injectable_signature::InjectableSignature;
 
 pub enum InjectInput {
     Constructor(ConstructorImpl),
     FreeFunction(FreeFunctionImpl),
+    Method(ImplItemMethod),
 }
 
 impl InjectInput {
-    pub fn name(&self) -> &Ident {
-        self.signature().ident()
-    }
-
-    pub fn inputs(&self) -> Vec<&Ident> {
-        self.signature().input_idents()
-    }
-
     pub fn signature(&self) -> &dyn InjectableSignature {
         match self {
             InjectInput::Constructor(method) => &method.sig,
             InjectInput::FreeFunction(method) => &method.sig,
+            InjectInput::Method(method) => &method.sig,
         }
     }
 }
 
 impl Parse for InjectInput {
     fn parse(input: ParseStream) -> Result<Self> {
-        if input.fork().parse::<ConstructorImpl>().is_ok() {
+        let method = input.fork().parse::<ImplItemMethod>();
+        if method.is_ok_and(|method| method.sig.receiver().is_some()) {
+            input.parse().map(InjectInput::Method)
+        } else if input.fork().parse::<ConstructorImpl>().is_ok() {
             input
                 .parse::<ConstructorImpl>()
                 .map(InjectInput::Constructor)
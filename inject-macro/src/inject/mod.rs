@@ -1,11 +1,15 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse, Ident, Result};
+use syn::{parse, parse_quote, Error, Ident, ImplItemMethod, Index, Result, Token, Visibility};
 
-use arguments::InjectArgs;
-use input::InjectInput;
+use arguments::{InjectArgs, Injection};
+use callable::Callable;
+use input::{ConstructorImpl, FreeFunctionImpl, InjectInput};
+
+use crate::BoolToOption;
 
 pub mod arguments;
+mod callable;
 mod input;
 
 pub struct Inject {
@@ -33,20 +37,31 @@ impl Inject {
     pub fn expand(self) -> TokenStream {
         let Self {
             origin,
-            args,
+            mut args,
             method,
         } = self;
 
-        let name = method.name();
-        let inputs = method.inputs();
+        let post_construct = args.take_post_construct();
         let args = match args.expand_signature(method.signature()) {
             Ok(parsed_args) => parsed_args,
             Err(compile_error) => return compile_error.to_compile_error(),
         };
 
-        let expansion = match method {
-            InjectInput::Constructor(_) => Self::expand_constructor(name, args),
-            InjectInput::FreeFunction(_) => Self::expand_free_function(name, inputs, args),
+        let expansion = match (&method, post_construct) {
+            (InjectInput::Constructor(constructor), post_construct) => {
+                Self::expand_constructor(constructor, args, post_construct)
+            }
+            (_, Some(post_construct)) => {
+                return Error::new(
+                    post_construct.span(),
+                    "'post_construct' is only supported on constructors",
+                )
+                .to_compile_error()
+            }
+            (InjectInput::FreeFunction(function), None) => {
+                Self::expand_free_function(function, args)
+            }
+            (InjectInput::Method(method), None) => Self::expand_method(method, args),
         };
 
         quote! {
@@ -56,61 +71,257 @@ impl Inject {
         }
     }
 
-    fn expand_constructor(name: &Ident, args: Vec<Option<TokenStream>>) -> TokenStream {
-        quote! {
-            pub fn inject(container: &::inject::Container) -> Result<Self, ::inject::InjectError> {
-                Ok(
-                    Self:: #name ( #(#args,)* )
+    /// Expands the associated `inject` and `inject_async` functions of a constructor, as well as
+    /// hidden functions constructing it with some arguments supplied explicitly, see
+    /// [`Inject::expand_supplied_calls`]. With a `post_construct` method, every constructed
+    /// instance is passed to a hidden `__inject_post_construct` function calling it.
+    fn expand_constructor(
+        constructor: &ConstructorImpl,
+        args: Vec<Injection>,
+        post_construct: Option<Ident>,
+    ) -> TokenStream {
+        let ConstructorImpl { vis, sig, .. } = constructor;
+        let name = &sig.ident;
+        let is_async = sig.asyncness.is_some();
+        let callable = Callable::new(sig, &parse_quote!(-> Self));
+        let mut generics = sig.generics.clone();
+        for param in generics.type_params_mut() {
+            param.bounds.push(parse_quote!(::inject::Inject));
+        }
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        let finish = match &post_construct {
+            Some(_) => quote! { Self::__inject_post_construct },
+            None => quote! { Ok },
+        };
+        let post_construct = post_construct.map(|method| {
+            quote! {
+                #[doc(hidden)]
+                #[allow(unused_mut)]
+                fn __inject_post_construct(mut instance: Self) -> Result<Self, ::inject::InjectError> {
+                    ::inject::lifecycle::PostConstruct::into_result(instance.#method())?;
+                    Ok(instance)
+                }
+            }
+        });
+
+        let async_args = args.iter().map(Injection::expand_async);
+        let awaiting = is_async.and(quote! { .await });
+        let inject_async = quote! {
+            pub async fn inject_async #impl_generics (container: &::inject::Container) -> Result<Self, ::inject::InjectError> #where_clause {
+                #finish(
+                    Self:: #name ( #(#async_args,)* ) #awaiting
                 )
             }
+        };
+        let inject = is_async.or_then(|| {
+            let args = args.iter().map(Injection::expand);
+            quote! {
+                pub fn inject #impl_generics (container: &::inject::Container) -> Result<Self, ::inject::InjectError> #where_clause {
+                    #finish(
+                        Self:: #name ( #(#args,)* )
+                    )
+                }
+            }
+        });
+        let dependencies = args
+            .iter()
+            .map(|injection| injection.expand_dependency(&sig.generics));
+        let supplied_calls = Self::expand_supplied_calls(
+            vis,
+            None,
+            is_async,
+            &callable,
+            &args,
+            None,
+            quote! { Self::#name },
+            &finish,
+            "",
+        );
+
+        quote! {
+            #inject
+            #inject_async
+            #post_construct
+
+            pub fn inject_dependencies() -> ::std::vec::Vec<::inject::graph::Dependency> {
+                ::std::vec![ #(#dependencies),* ]
+            }
+
+            #supplied_calls
         }
     }
 
-    fn expand_free_function(
-        name: &Ident,
-        inputs: Vec<&Ident>,
-        args: Vec<Option<TokenStream>>,
-    ) -> TokenStream {
-        let macro_name = format_ident!("__inject_{}", name);
-        let macro_expansion = format_ident!("{}_expand", macro_name);
+    /// Expands a hidden `__inject_<name>` struct next to the function, holding an optional value
+    /// for each argument. Its `call` method resolves the missing ones and calls the function.
+    fn expand_free_function(function: &FreeFunctionImpl, args: Vec<Injection>) -> TokenStream {
+        let FreeFunctionImpl { vis, sig, .. } = function;
+        let name = &sig.ident;
+        let helper = format_ident!("__inject_{}", name);
+        let unsafety = &sig.unsafety;
+        let callable = Callable::new(sig, &sig.output);
+        let Callable {
+            inputs,
+            input_types,
+            output,
+            generics,
+            turbofish,
+            marker,
+        } = &callable;
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-        let expand_macro = quote! {
+        let supplied: Vec<_> = inputs.iter().map(|input| quote! { self.#input }).collect();
+        let async_args = callable.arguments(
+            &supplied,
+            &args.iter().map(Injection::expand_async).collect::<Vec<_>>(),
+        );
+        let awaiting = sig.asyncness.map(|_| quote! { .await });
+        let call = sig.asyncness.is_none().and_then(|| {
+            let args = callable.arguments(
+                &supplied,
+                &args.iter().map(Injection::expand).collect::<Vec<_>>(),
+            );
+            quote! {
+                pub #unsafety fn call(self, container: &'__inject ::inject::Container) -> Result<#output, ::inject::InjectError> {
+                    Ok(#unsafety { #name #turbofish ( #args ) })
+                }
+            }
+        });
+
+        quote! {
             #[doc(hidden)]
-            #[macro_export]
-            macro_rules! #macro_expansion {
-                ( ) => { };
-                #( ( $container:expr, #inputs #inputs : $arg:expr ) => {
-                    $arg
-                };
-                ($container:expr, #inputs) => {
-                    {
-                        let container = $container;
-                        #args
+            #[allow(dead_code, non_camel_case_types)]
+            #vis struct #helper #impl_generics #where_clause {
+                #( #vis #inputs: ::std::option::Option<#input_types>, )*
+                __marker: #marker,
+            }
+
+            #[allow(dead_code)]
+            impl #impl_generics ::std::default::Default for #helper #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self {
+                        #( #inputs: ::std::option::Option::None, )*
+                        __marker: ::std::marker::PhantomData,
                     }
+                }
+            }
 
-                };
-            )*}
-        };
+            #[allow(dead_code)]
+            impl #impl_generics #helper #ty_generics #where_clause {
+                #call
 
-        let inject_macro = quote! {
-            #[doc(hidden)]
-            #[macro_export]
-            macro_rules! #macro_name {
-                ($container:expr #( $(, #inputs : $#inputs:expr )?  )* ) => {
-                    {
-                        let __helper = |__container: &::inject::Container| {
-                            Ok(#name ( #( #macro_expansion ! ( __container, #inputs $( #inputs : $#inputs )? ) ,)* ))
-                        };
-                        let result: Result<_, ::inject::InjectError> = __helper($container);
-                        result
-                    }
-                };
+                pub async #unsafety fn call_async(self, container: &'__inject ::inject::Container) -> Result<#output, ::inject::InjectError> {
+                    Ok(#unsafety { #name #turbofish ( #async_args ) } #awaiting)
+                }
             }
-        };
+        }
+    }
+
+    /// Expands hidden methods next to a `&self` method, calling it with some arguments supplied
+    /// explicitly, see [`Inject::expand_supplied_calls`].
+    fn expand_method(method: &ImplItemMethod, args: Vec<Injection>) -> TokenStream {
+        let ImplItemMethod { vis, sig, .. } = method;
+        let name = &sig.ident;
+        let callable = Callable::new(sig, &sig.output);
+
+        Self::expand_supplied_calls(
+            vis,
+            sig.unsafety.as_ref(),
+            sig.asyncness.is_some(),
+            &callable,
+            &args,
+            Some(quote! { &self, }),
+            quote! { self.#name },
+            &quote! { Ok },
+            &format!("_{}", name),
+        )
+    }
+
+    /// Expands hidden associated functions calling `callee` with an optional value for each
+    /// argument, held by a tuple. `__inject_args<suffix>` creates the tuple,
+    /// `__inject_set<suffix>__<arg>` supplies an argument, and `__inject_call<suffix>` and
+    /// `__inject_await<suffix>` resolve the missing ones and call `callee`, passing its result to
+    /// `finish`.
+    #[allow(clippy::too_many_arguments)]
+    fn expand_supplied_calls(
+        vis: &Visibility,
+        unsafety: Option<&Token![unsafe]>,
+        is_async: bool,
+        callable: &Callable,
+        args: &[Injection],
+        receiver: Option<TokenStream>,
+        callee: TokenStream,
+        finish: &TokenStream,
+        suffix: &str,
+    ) -> TokenStream {
+        let Callable {
+            inputs,
+            input_types,
+            output,
+            generics,
+            turbofish,
+            ..
+        } = callable;
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let call_receiver = receiver.as_ref().map(|_| quote! { &'__inject self, });
+
+        let arguments = quote! { ( #( ::std::option::Option<#input_types>, )* ) };
+        let indices: Vec<_> = (0..inputs.len()).map(Index::from).collect();
+        let supplied: Vec<_> = indices.iter().map(|index| quote! { args.#index }).collect();
+
+        let args_fn = format_ident!("__inject_args{}", suffix);
+        let nones = inputs
+            .iter()
+            .map(|_| quote! { ::std::option::Option::None });
+        let setters = inputs.iter().zip(input_types).zip(&indices).map(|((input, ty), index)| {
+            let setter = format_ident!("__inject_set{}__{}", suffix, input);
+            quote! {
+                #[doc(hidden)]
+                #[allow(dead_code, non_snake_case, clippy::type_complexity)]
+                #vis fn #setter #impl_generics (#receiver args: &mut #arguments, value: #ty) #where_clause {
+                    args.#index = ::std::option::Option::Some(value);
+                }
+            }
+        });
+
+        let call_fn = format_ident!("__inject_call{}", suffix);
+        let call = is_async.or_then(|| {
+            let args = callable.arguments(
+                &supplied,
+                &args.iter().map(Injection::expand).collect::<Vec<_>>(),
+            );
+            quote! {
+                #[doc(hidden)]
+                #[allow(dead_code, non_snake_case, clippy::type_complexity)]
+                #vis #unsafety fn #call_fn #impl_generics (#call_receiver container: &'__inject ::inject::Container, args: #arguments) -> Result<#output, ::inject::InjectError> #where_clause {
+                    #finish(#unsafety { #callee #turbofish ( #args ) })
+                }
+            }
+        });
+
+        let await_fn = format_ident!("__inject_await{}", suffix);
+        let async_args = callable.arguments(
+            &supplied,
+            &args.iter().map(Injection::expand_async).collect::<Vec<_>>(),
+        );
+        let awaiting = is_async.and(quote! { .await });
 
         quote! {
-            #expand_macro
-            #inject_macro
+            #[doc(hidden)]
+            #[allow(dead_code, non_snake_case, clippy::type_complexity, clippy::unused_unit)]
+            #vis fn #args_fn #impl_generics (#receiver) -> #arguments #where_clause {
+                ( #( #nones, )* )
+            }
+
+            #( #setters )*
+
+            #call
+
+            #[doc(hidden)]
+            #[allow(dead_code, non_snake_case, clippy::type_complexity)]
+            #vis async #unsafety fn #await_fn #impl_generics (#call_receiver container: &'__inject ::inject::Container, args: #arguments) -> Result<#output, ::inject::InjectError> #where_clause {
+                #finish(#unsafety { #callee #turbofish ( #async_args ) } #awaiting)
+            }
         }
     }
 }
@@ -1,6 +1,7 @@
 use bool_to_option::BoolToOption;
 use call::Call;
 use container::Container;
+use derive::DeriveInject;
 use get::Get;
 use inject::Inject;
 use syn::parse_macro_input;
@@ -8,6 +9,7 @@ use syn::parse_macro_input;
 mod bool_to_option;
 mod call;
 mod container;
+mod derive;
 mod get;
 mod inject;
 
@@ -37,3 +39,8 @@ pub fn call(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn container(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     parse_macro_input!(input as Container).expand().into()
 }
+
+#[proc_macro_derive(Inject, attributes(inject))]
+pub fn derive_inject(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    parse_macro_input!(input as DeriveInject).expand().into()
+}
@@ -0,0 +1,195 @@
+use std::future::{Future, Ready};
+use std::sync::Arc;
+
+use crate::bindings::Entry;
+use crate::graph::{Lifetime, ProviderInfo, ProviderKind};
+use crate::inject::Inject;
+use crate::key::Key;
+use crate::optional::optional;
+use crate::provider::AsyncProvider;
+use crate::resolution::{self, Resolving};
+use crate::Container;
+use crate::InjectError;
+
+/// The nearest binding of a type, when resolving it asynchronously.
+#[allow(clippy::type_complexity)]
+enum AsyncBinding<'a, T> {
+    Async(
+        Arc<Box<dyn AsyncProvider<ProvidedType = T> + Send + Sync>>,
+        &'a Container,
+    ),
+    Sync,
+    Missing,
+}
+
+impl Container {
+    /// Install an [`AsyncProvider`](provider/trait.AsyncProvider.html) into this `Container`.
+    /// Types provided asynchronously are only resolved by
+    /// [`container.get_async()`](struct.Container.html#method.get_async).
+    pub fn install_async<T: Inject, P: 'static + AsyncProvider<ProvidedType = T> + Send + Sync>(
+        &mut self,
+        provider: P,
+    ) {
+        self.push(Self::async_entry(None, provider));
+    }
+
+    /// Install an [`AsyncProvider`](provider/trait.AsyncProvider.html) into this `Container`,
+    /// qualified by `name`.
+    pub fn install_async_named<
+        T: Inject,
+        P: 'static + AsyncProvider<ProvidedType = T> + Send + Sync,
+    >(
+        &mut self,
+        name: &'static str,
+        provider: P,
+    ) {
+        self.push(Self::async_entry(Some(name), provider));
+    }
+
+    /// Resolve a value-type asynchronously, from the nearest installed
+    /// [`AsyncProvider`](provider/trait.AsyncProvider.html) or
+    /// [`Provider`](provider/trait.Provider.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{container, Container};
+    /// use inject::provider::async_fn;
+    ///
+    /// let container = container![
+    ///     async async_fn(|_: &Container| Box::pin(async { Ok(5usize) })),
+    ///     |_: &Container| Ok(2i32),
+    /// ];
+    ///
+    /// assert_eq!(Ok(5usize), pollster::block_on(container.get_async()));
+    /// assert_eq!(Ok(2i32), pollster::block_on(container.get_async()));
+    /// ```
+    pub async fn get_async<T: Inject>(&self) -> Result<T, InjectError> {
+        self.resolve_async(None, None::<Ready<_>>).await
+    }
+
+    /// Resolve a value-type asynchronously, from the provider installed under `name`.
+    pub async fn get_async_named<T: Inject>(&self, name: &'static str) -> Result<T, InjectError> {
+        self.resolve_async(Some(name), None::<Ready<_>>).await
+    }
+
+    /// Resolve a value-type like [`container.get_async()`](struct.Container.html#method.get_async),
+    /// but create it by awaiting `fallback` if no provider is installed for it.
+    pub async fn get_async_or_else<T: Inject>(
+        &self,
+        fallback: impl Future<Output = Result<T, InjectError>>,
+    ) -> Result<T, InjectError> {
+        self.resolve_async(None, Some(fallback)).await
+    }
+
+    /// Resolve a value-type like [`container.get_async()`](struct.Container.html#method.get_async),
+    /// or `None` if no provider is installed for it.
+    pub async fn try_get_async<T: Inject>(&self) -> Result<Option<T>, InjectError> {
+        self.try_resolve_async(None, None::<Ready<_>>).await
+    }
+
+    /// Resolve a value-type like
+    /// [`container.try_get_async()`](struct.Container.html#method.try_get_async), but create it
+    /// by awaiting `fallback` if no provider is installed for it. Resolves to `None` if `fallback`
+    /// fails for lack of a provider.
+    pub async fn try_get_async_or_else<T: Inject>(
+        &self,
+        fallback: impl Future<Output = Result<T, InjectError>>,
+    ) -> Result<Option<T>, InjectError> {
+        self.try_resolve_async(None, Some(fallback)).await
+    }
+
+    /// Resolve a value-type asynchronously, from the provider installed under `name`, or `None`
+    /// if there is none.
+    pub async fn try_get_async_named<T: Inject>(
+        &self,
+        name: &'static str,
+    ) -> Result<Option<T>, InjectError> {
+        self.try_resolve_async(Some(name), None::<Ready<_>>).await
+    }
+
+    async fn try_resolve_async<T: Inject>(
+        &self,
+        name: Option<&'static str>,
+        fallback: Option<impl Future<Output = Result<T, InjectError>>>,
+    ) -> Result<Option<T>, InjectError> {
+        let binding = {
+            let _resolving = Resolving::enter::<T>(name, self)?;
+            self.async_binding::<T>(name)?
+        };
+        match (binding, fallback) {
+            (AsyncBinding::Missing, None) => Ok(None),
+            (AsyncBinding::Missing, fallback) => optional(self.resolve_async(name, fallback).await),
+            _ => self.resolve_async(name, None::<Ready<_>>).await.map(Some),
+        }
+    }
+
+    async fn resolve_async<T: Inject>(
+        &self,
+        name: Option<&'static str>,
+        fallback: Option<impl Future<Output = Result<T, InjectError>>>,
+    ) -> Result<T, InjectError> {
+        let binding = {
+            let _resolving = Resolving::enter::<T>(name, self)?;
+            self.async_binding::<T>(name)?
+        };
+        match (binding, fallback) {
+            (AsyncBinding::Async(provider, container), _) => {
+                let container = container.on_behalf_of(self, Lifetime::Transient);
+                resolution::resolving::<T, _, _>(name, self, provider.provide_async(&container))
+                    .await
+            }
+            (AsyncBinding::Missing, Some(fallback)) => {
+                resolution::resolving::<T, _, _>(name, self, fallback).await
+            }
+            _ => match name {
+                Some(name) => self.get_named(name),
+                None => self.get(),
+            },
+        }
+    }
+
+    /// The nearest binding of `T` in this container or its ancestors, preferring an asynchronous
+    /// one when a container has both.
+    fn async_binding<T: 'static>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Result<AsyncBinding<'_, T>, InjectError> {
+        for container in self.lineage() {
+            if let Some(provider) = container.bindings.get(Key::of_async::<T>(name)) {
+                let provider = provider
+                    .downcast::<Box<dyn AsyncProvider<ProvidedType = T> + Send + Sync>>()
+                    .map_err(|_| InjectError::failed_cast())?;
+                return Ok(AsyncBinding::Async(provider, container));
+            }
+            if container.bindings.contains(Key::of::<T>(name)) {
+                return Ok(AsyncBinding::Sync);
+            }
+        }
+        Ok(AsyncBinding::Missing)
+    }
+
+    pub(crate) fn async_entry<
+        T: Inject,
+        P: 'static + AsyncProvider<ProvidedType = T> + Send + Sync,
+    >(
+        name: Option<&'static str>,
+        provider: P,
+    ) -> Entry {
+        Entry::new(
+            Key::Async(provider.id(), name),
+            ProviderInfo::of::<T>(name, ProviderKind::Async),
+            Arc::new(Self::box_async_provider(provider)),
+            None,
+        )
+    }
+
+    fn box_async_provider<
+        T: 'static,
+        P: 'static + AsyncProvider<ProvidedType = T> + Send + Sync,
+    >(
+        provider: P,
+    ) -> Box<dyn AsyncProvider<ProvidedType = T> + Send + Sync> {
+        Box::new(provider)
+    }
+}
@@ -0,0 +1,178 @@
+use std::sync::{Arc, OnceLock};
+
+use crate::inject::Inject;
+use crate::provider::RefProvider;
+use crate::Container;
+use crate::InjectError;
+
+/// Creates the boxed instance of a bound type.
+type Create<T> = dyn Fn(&Container) -> Result<Box<T>, InjectError> + Send + Sync;
+
+/// Provides a reference to the instance bound to `T`, created on first use using the container
+/// the binding was installed into.
+struct BoundRef<T: ?Sized> {
+    create: Arc<Create<T>>,
+    instance: OnceLock<Box<T>>,
+}
+
+impl<T: ?Sized + Send + Sync + 'static> RefProvider for BoundRef<T> {
+    type ProvidedRef = T;
+
+    fn provide<'a>(&'a self, container: &'a Container) -> Result<&'a T, InjectError> {
+        if let Some(instance) = self.instance.get() {
+            return Ok(instance);
+        }
+        let instance = (self.create)(container)?;
+        Ok(self.instance.get_or_init(|| instance))
+    }
+}
+
+/// An implementation of `T`, most commonly a trait object, which can be bound to it using
+/// [`container.bind::<T, Self>()`](../struct.Container.html#method.bind).
+///
+/// Coercing a type into a trait object can not be expressed generically, so implementations are
+/// generated for concrete types using [`implements!`](../macro.implements.html).
+pub trait Implements<T: ?Sized>: Inject {
+    /// Resolves an instance of this type like [`get!`](../macro.get.html), coerced into `T`.
+    fn create(container: &Container) -> Result<Box<T>, InjectError>;
+}
+
+/// Implements [`Implements`](binding/trait.Implements.html) for a type, so that it can be bound to
+/// a trait object it implements using
+/// [`container.bind::<dyn Trait, Impl>()`](struct.Container.html#method.bind).
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use inject::{get, implements, Container};
+///
+/// trait Greeter: Send + Sync {
+///     fn greet(&self) -> &'static str;
+/// }
+///
+/// #[derive(Default)]
+/// struct Hello;
+///
+/// impl Greeter for Hello {
+///     fn greet(&self) -> &'static str {
+///         "Hello"
+///     }
+/// }
+///
+/// implements!(dyn Greeter => Hello);
+///
+/// let mut container = Container::new();
+/// container.bind::<dyn Greeter, Hello>();
+///
+/// assert_eq!("Hello", get!(&container, Arc<dyn Greeter>).unwrap().greet());
+/// ```
+#[macro_export]
+macro_rules! implements {
+    ($trait_object:ty => $implementation:ty) => {
+        impl $crate::binding::Implements<$trait_object> for $implementation {
+            fn create(
+                container: &$crate::Container,
+            ) -> Result<Box<$trait_object>, $crate::InjectError> {
+                $crate::get!(container, $implementation)
+                    .map(|instance| Box::new(instance) as Box<$trait_object>)
+            }
+        }
+    };
+}
+
+impl Container {
+    /// Bind a type, most commonly a trait object, to an implementation `I`, resolved like
+    /// [`get!`](macro.get.html) and coerced into `T`. Resolves `Box<T>` and `Arc<T>` to a new
+    /// instance every time, and `&T` to an instance created once.
+    ///
+    /// The implementation is coerced using [`Implements`](binding/trait.Implements.html), generated
+    /// by [`implements!`](macro.implements.html). [`container!`](macro.container.html) binds
+    /// entries like `dyn Trait => Impl` without it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{container, get, implements, Container};
+    ///
+    /// trait Greeter: Send + Sync {
+    ///     fn greet(&self) -> &'static str;
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Hello;
+    ///
+    /// impl Greeter for Hello {
+    ///     fn greet(&self) -> &'static str {
+    ///         "Hello"
+    ///     }
+    /// }
+    ///
+    /// implements!(dyn Greeter => Hello);
+    ///
+    /// let mut container = Container::new();
+    /// container.bind::<dyn Greeter, Hello>();
+    ///
+    /// assert_eq!("Hello", get!(&container, Box<dyn Greeter>).unwrap().greet());
+    ///
+    /// let container = container![dyn Greeter => Hello];
+    ///
+    /// assert_eq!("Hello", get!(&container, &dyn Greeter).unwrap().greet());
+    /// ```
+    pub fn bind<T: ?Sized + Send + Sync + 'static, I: Implements<T>>(&mut self) {
+        self.bind_with(I::create)
+    }
+
+    /// Bind a type, most commonly a trait object, to the instances created by `create`, like
+    /// [`container.bind::<T, _>()`](struct.Container.html#method.bind).
+    ///
+    /// `create` is typically a provider for an implementation, which coerces the implementation
+    /// into a `Box<T>`. [`container!`](macro.container.html) generates it for entries like
+    /// `dyn Trait => Impl`, resolving `Impl` using [`get!`](macro.get.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use inject::{container, get, Container};
+    ///
+    /// trait Greeter: Send + Sync {
+    ///     fn greet(&self) -> &'static str;
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Hello;
+    ///
+    /// impl Greeter for Hello {
+    ///     fn greet(&self) -> &'static str {
+    ///         "Hello"
+    ///     }
+    /// }
+    ///
+    /// let mut container = Container::new();
+    /// container.bind_with::<dyn Greeter>(|_: &Container| Ok(Box::new(Hello)));
+    ///
+    /// assert_eq!("Hello", get!(&container, Arc<dyn Greeter>).unwrap().greet());
+    /// assert_eq!("Hello", get!(&container, &dyn Greeter).unwrap().greet());
+    /// ```
+    pub fn bind_with<T: ?Sized + Send + Sync + 'static>(
+        &mut self,
+        create: impl Fn(&Container) -> Result<Box<T>, InjectError> + Send + Sync + 'static,
+    ) {
+        let create: Arc<Create<T>> = Arc::new(create);
+        self.install({
+            let create = Arc::clone(&create);
+            move |container: &Container| create(container)
+        });
+        self.install({
+            let create = Arc::clone(&create);
+            move |container: &Container| create(container).map(Arc::<T>::from)
+        });
+        self.install_ref(BoundRef {
+            create,
+            instance: OnceLock::new(),
+        });
+    }
+}
@@ -0,0 +1,184 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::graph::ProviderInfo;
+use crate::key::Key;
+use crate::validation::Resolve;
+
+/// The providers installed into a [`Container`](../struct.Container.html), by key.
+///
+/// Bindings are shared by a container and its children while still being installed into, so
+/// they are kept behind a lock. A key maps to the provider installed for it, or to every
+/// contribution to a set, map or decorator chain, in installation order. Replaced and removed
+/// providers are dropped, except for reference providers which handed out references: those are
+/// retained until the bindings drop, as the references live as long as the container itself.
+///
+/// The bindings also own what their singletons release on
+/// [`container.shutdown()`](../struct.Container.html#method.shutdown), in creation order: they
+/// forget their instances, and dispose the [`Disposable`](../lifecycle/trait.Disposable.html) ones.
+#[derive(Default)]
+pub(crate) struct Bindings {
+    entries: RwLock<HashMap<Key, Vec<Entry>>>,
+    /// The number of entries installed so far, which orders them across keys.
+    installed: AtomicUsize,
+    retained: Mutex<Vec<Arc<dyn Any + Send + Sync>>>,
+    pub releases: Mutex<Vec<Box<dyn FnOnce() + Send>>>,
+}
+
+/// An installed provider, together with what is known about it without knowing its type.
+#[derive(Clone)]
+pub(crate) struct Entry {
+    pub key: Key,
+    pub info: ProviderInfo,
+    pub provider: Arc<dyn Any + Send + Sync>,
+    /// Resolves the binding of `key`, if it can be resolved synchronously.
+    pub resolve: Option<Resolve>,
+    /// Whether the provider is invoked when the container is built.
+    pub eager: bool,
+    /// The position of the entry in installation order.
+    order: usize,
+    /// Whether references were handed out by the provider.
+    lent: Arc<AtomicBool>,
+}
+
+impl Entry {
+    pub fn new(
+        key: Key,
+        info: ProviderInfo,
+        provider: Arc<dyn Any + Send + Sync>,
+        resolve: Option<Resolve>,
+    ) -> Self {
+        Self {
+            key,
+            info,
+            provider,
+            resolve,
+            eager: false,
+            order: 0,
+            lent: Arc::default(),
+        }
+    }
+}
+
+impl Bindings {
+    /// Install a provider, replacing the one installed for its key unless it contributes to a
+    /// set, map or decorator chain.
+    pub fn push(&self, entry: Entry) {
+        let replaced = self.insert(&mut self.entries.write().unwrap(), entry);
+        self.retain_lent(replaced);
+    }
+
+    /// Install a provider unless one is already installed for its key, returning whether it was
+    /// installed. The check and the installation happen under the same lock.
+    pub fn try_push(&self, entry: Entry) -> bool {
+        let mut entries = self.entries.write().unwrap();
+        if entries.contains_key(&entry.key) {
+            return false;
+        }
+        self.insert(&mut entries, entry);
+        true
+    }
+
+    /// Insert `entry` into the locked `entries`, returning the entries it replaced.
+    fn insert(&self, entries: &mut HashMap<Key, Vec<Entry>>, mut entry: Entry) -> Vec<Entry> {
+        entry.order = self.installed.fetch_add(1, Ordering::Relaxed);
+        let installed = entries.entry(entry.key).or_default();
+        match entry.key {
+            Key::Set(_) | Key::Map(..) | Key::Decorator(_) => {
+                installed.push(entry);
+                Vec::new()
+            }
+            _ => std::mem::replace(installed, vec![entry]),
+        }
+    }
+
+    /// Remove every provider installed for `key`, returning whether there was one.
+    pub fn remove(&self, key: Key) -> bool {
+        let removed = self.entries.write().unwrap().remove(&key);
+        match removed {
+            Some(removed) => {
+                self.retain_lent(removed);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn retain_lent(&self, entries: Vec<Entry>) {
+        let lent = entries
+            .into_iter()
+            .filter(|entry| entry.lent.load(Ordering::SeqCst))
+            .map(|entry| entry.provider);
+        self.retained.lock().unwrap().extend(lent);
+    }
+
+    /// Whether a provider is installed for `key`.
+    pub fn contains(&self, key: Key) -> bool {
+        self.entries.read().unwrap().contains_key(&key)
+    }
+
+    /// The provider installed for `key`, or the most recent contribution to it.
+    pub fn get(&self, key: Key) -> Option<Arc<dyn Any + Send + Sync>> {
+        let entries = self.entries.read().unwrap();
+        entries
+            .get(&key)?
+            .last()
+            .map(|entry| entry.provider.clone())
+    }
+
+    /// The reference provider installed for `key`, which is retained until the bindings drop
+    /// from now on, even if it is replaced or removed.
+    pub fn lend(&self, key: Key) -> Option<Arc<dyn Any + Send + Sync>> {
+        let entries = self.entries.read().unwrap();
+        let entry = entries.get(&key)?.last()?;
+        entry.lent.store(true, Ordering::SeqCst);
+        Some(entry.provider.clone())
+    }
+
+    /// Every provider installed for `key`, in installation order.
+    pub fn get_all(&self, key: Key) -> Vec<Arc<dyn Any + Send + Sync>> {
+        let entries = self.entries.read().unwrap();
+        entries
+            .get(&key)
+            .into_iter()
+            .flatten()
+            .map(|entry| entry.provider.clone())
+            .collect()
+    }
+
+    /// Every installed entry, in installation order.
+    pub fn iter(&self) -> impl Iterator<Item = Entry> {
+        let mut entries: Vec<_> = self
+            .entries
+            .read()
+            .unwrap()
+            .values()
+            .flatten()
+            .cloned()
+            .collect();
+        entries.sort_by_key(|entry| entry.order);
+        entries.into_iter()
+    }
+}
+
+impl Clone for Bindings {
+    fn clone(&self) -> Self {
+        Self {
+            entries: RwLock::new(self.entries.read().unwrap().clone()),
+            installed: AtomicUsize::new(self.installed.load(Ordering::Relaxed)),
+            retained: Mutex::default(),
+            releases: Mutex::default(),
+        }
+    }
+}
+
+impl Debug for Bindings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list()
+            .entries(self.iter().map(|entry| entry.key))
+            .finish()
+    }
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use crate::bindings::Entry;
+use crate::graph::{Lifetime, ProviderInfo, ProviderKind};
+use crate::inject::Inject;
+use crate::key::Key;
+use crate::Container;
+use crate::InjectError;
+
+/// Wraps a value provided by an installed provider.
+type Decorator<T> = dyn Fn(T, &Container) -> Result<T, InjectError> + Send + Sync;
+
+impl Container {
+    /// Wrap every value of type `T` provided by an installed
+    /// [`Provider`](provider/trait.Provider.html) using `decorator`, without replacing the
+    /// provider, for instance to add caching or metrics around a trait object.
+    ///
+    /// Decorators are applied by [`container.get()`](struct.Container.html#method.get),
+    /// [`container.get_named(..)`](struct.Container.html#method.get_named) and their variants,
+    /// including [`get!`](macro.get.html), after the provider runs, in the order they were
+    /// installed, each receiving the value returned by the one before. Decorators installed into
+    /// the ancestors of this container are applied first, and called with the container they were
+    /// installed into. Values created without an installed provider, or provided asynchronously,
+    /// are not decorated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use inject::{container, get, Container};
+    ///
+    /// trait Repo: Send + Sync {
+    ///     fn find(&self) -> String;
+    /// }
+    ///
+    /// struct Postgres;
+    ///
+    /// impl Repo for Postgres {
+    ///     fn find(&self) -> String {
+    ///         String::from("postgres")
+    ///     }
+    /// }
+    ///
+    /// struct Cached(Arc<dyn Repo>);
+    ///
+    /// impl Repo for Cached {
+    ///     fn find(&self) -> String {
+    ///         format!("cached {}", self.0.find())
+    ///     }
+    /// }
+    ///
+    /// let mut container = container![|_: &Container| Ok(Arc::new(Postgres) as Arc<dyn Repo>)];
+    /// container.decorate(|inner: Arc<dyn Repo>, _: &Container| {
+    ///     Ok(Arc::new(Cached(inner)) as Arc<dyn Repo>)
+    /// });
+    ///
+    /// let repo = get!(&container, Arc<dyn Repo>).unwrap();
+    /// assert_eq!("cached postgres", repo.find());
+    /// ```
+    pub fn decorate<T: Inject>(
+        &mut self,
+        decorator: impl Fn(T, &Container) -> Result<T, InjectError> + Send + Sync + 'static,
+    ) {
+        let decorator: Box<Decorator<T>> = Box::new(decorator);
+        self.bindings.push(Entry::new(
+            Key::decorator::<T>(),
+            ProviderInfo::of::<T>(None, ProviderKind::Decorator),
+            Arc::new(decorator),
+            None,
+        ));
+    }
+
+    /// `value`, passed through every decorator of its type.
+    pub(crate) fn decorated<T: 'static>(&self, value: T) -> Result<T, InjectError> {
+        self.contributions(Key::decorator::<T>())
+            .into_iter()
+            .try_fold(value, |value, (decorator, container)| {
+                let decorator = decorator
+                    .downcast_ref::<Box<Decorator<T>>>()
+                    .ok_or_else(InjectError::failed_cast)?;
+                decorator(value, &container.on_behalf_of(self, Lifetime::Transient))
+            })
+    }
+}
@@ -2,26 +2,255 @@
 //!
 //! When injection fails due to a provider not being available, or a downcast has gone awry.
 //!
-//! Most probably, the encountered error will be `InjectError::MissingProvider`.
+//! Most probably, the encountered error will be `InjectError::MissingProvider`. Every error
+//! carries the name of the type that failed to resolve, and the path of types that were being
+//! resolved when it did, outermost first. Use [`error.kind()`](enum.InjectError.html#method.kind)
+//! to match on the kind of failure alone.
+//!
+//! # Example
+//!
+//! ```
+//! use ::inject::{container, get, inject, InjectErrorKind};
+//!
+//! struct Pool;
+//!
+//! struct Repo;
+//!
+//! impl Repo {
+//!     #[inject]
+//!     fn new(pool: &Pool) -> Self {
+//!         Self
+//!     }
+//! }
+//!
+//! let container = container![];
+//!
+//! let error = get!(&container, Repo).err().unwrap();
+//!
+//! assert_eq!(error.kind(), InjectErrorKind::MissingProvider);
+//! assert!(error.type_name().ends_with("Pool"));
+//! assert!(error.path()[0].ends_with("Repo"));
+//! assert_eq!(error.path().len(), 2);
+//! ```
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
+use std::sync::Arc;
+
+use crate::resolution;
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub enum InjectError {
     /// Returned when down-casting has failed for the installed provider. Very rare.
-    FailedCast,
+    FailedCast {
+        type_name: &'static str,
+        path: Vec<&'static str>,
+    },
     /// Returned when a provider for the type is not present within
     /// the [`Container`](../struct.Container.html)
+    MissingProvider {
+        type_name: &'static str,
+        path: Vec<&'static str>,
+    },
+    /// Returned when a [`scoped!`](../macro.scoped.html) provider is resolved using a
+    /// [`Container`](../struct.Container.html) that is not within a scope.
+    MissingScope {
+        type_name: &'static str,
+        path: Vec<&'static str>,
+    },
+    /// Returned when resolving the type requires resolving itself. `cycle` lists the types
+    /// involved, starting and ending with the type.
+    Cycle {
+        type_name: &'static str,
+        path: Vec<&'static str>,
+        cycle: Vec<&'static str>,
+    },
+    /// Returned when a provider failed with an error of its own, which is kept as `source`.
+    ProviderFailed {
+        type_name: &'static str,
+        path: Vec<&'static str>,
+        source: Arc<dyn Error + Send + Sync>,
+    },
+    /// Returned when installing a provider for a type that already has one, using
+    /// [`container.try_install(..)`](../struct.Container.html#method.try_install).
+    DuplicateBinding {
+        type_name: &'static str,
+        path: Vec<&'static str>,
+    },
+}
+
+/// The kind of an [`InjectError`](enum.InjectError.html), without its context.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum InjectErrorKind {
+    FailedCast,
     MissingProvider,
+    MissingScope,
+    Cycle,
+    ProviderFailed,
+    DuplicateBinding,
+}
+
+impl InjectError {
+    /// A `FailedCast` error for the type currently being resolved.
+    pub fn failed_cast() -> Self {
+        let (type_name, path) = Self::context();
+        InjectError::FailedCast { type_name, path }
+    }
+
+    /// A `MissingProvider` error for the type currently being resolved.
+    pub fn missing_provider() -> Self {
+        let (type_name, path) = Self::context();
+        InjectError::MissingProvider { type_name, path }
+    }
+
+    /// A `MissingScope` error for the type currently being resolved.
+    pub fn missing_scope() -> Self {
+        let (type_name, path) = Self::context();
+        InjectError::MissingScope { type_name, path }
+    }
+
+    /// A `ProviderFailed` error for the type currently being resolved, caused by `source`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use inject::{container, get, Container, InjectError, InjectErrorKind};
+    ///
+    /// let container = container![
+    ///     |_: &Container| "http".parse::<u16>().map_err(InjectError::provider_failed)
+    /// ];
+    ///
+    /// let error = get!(&container, u16).unwrap_err();
+    ///
+    /// assert_eq!(error.kind(), InjectErrorKind::ProviderFailed);
+    /// assert!(error.source().is_some());
+    /// ```
+    pub fn provider_failed(source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        let (type_name, path) = Self::context();
+        InjectError::ProviderFailed {
+            type_name,
+            path,
+            source: Arc::from(source.into()),
+        }
+    }
+
+    /// A `Cycle` error for the type ending `cycle`, which is already being resolved.
+    pub(crate) fn cycle(cycle: Vec<&'static str>) -> Self {
+        let (_, mut path) = Self::context();
+        let type_name = cycle.last().copied().unwrap_or("<unknown>");
+        path.push(type_name);
+        InjectError::Cycle {
+            type_name,
+            path,
+            cycle,
+        }
+    }
+
+    /// A `DuplicateBinding` error for the type named `type_name`.
+    pub(crate) fn duplicate_binding(type_name: &'static str) -> Self {
+        InjectError::DuplicateBinding {
+            type_name,
+            path: vec![type_name],
+        }
+    }
+
+    /// The kind of this error.
+    pub fn kind(&self) -> InjectErrorKind {
+        match self {
+            InjectError::FailedCast { .. } => InjectErrorKind::FailedCast,
+            InjectError::MissingProvider { .. } => InjectErrorKind::MissingProvider,
+            InjectError::MissingScope { .. } => InjectErrorKind::MissingScope,
+            InjectError::Cycle { .. } => InjectErrorKind::Cycle,
+            InjectError::ProviderFailed { .. } => InjectErrorKind::ProviderFailed,
+            InjectError::DuplicateBinding { .. } => InjectErrorKind::DuplicateBinding,
+        }
+    }
+
+    /// The name of the type that failed to resolve, as given by `std::any::type_name`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            InjectError::FailedCast { type_name, .. }
+            | InjectError::MissingProvider { type_name, .. }
+            | InjectError::MissingScope { type_name, .. }
+            | InjectError::Cycle { type_name, .. }
+            | InjectError::ProviderFailed { type_name, .. }
+            | InjectError::DuplicateBinding { type_name, .. } => type_name,
+        }
+    }
+
+    /// The types that were being resolved when this error occurred, outermost first, ending with
+    /// the type that failed to resolve.
+    pub fn path(&self) -> &[&'static str] {
+        match self {
+            InjectError::FailedCast { path, .. }
+            | InjectError::MissingProvider { path, .. }
+            | InjectError::MissingScope { path, .. }
+            | InjectError::Cycle { path, .. }
+            | InjectError::ProviderFailed { path, .. }
+            | InjectError::DuplicateBinding { path, .. } => path,
+        }
+    }
+
+    fn context() -> (&'static str, Vec<&'static str>) {
+        let path = resolution::path();
+        let type_name = path.last().copied().unwrap_or("<unknown>");
+        (type_name, path)
+    }
+}
+
+impl PartialEq for InjectError {
+    /// Errors are equal when their kind, type name and path are, and for `ProviderFailed`, when
+    /// their sources display the same.
+    fn eq(&self, other: &Self) -> bool {
+        let same_source = match (self, other) {
+            (
+                InjectError::ProviderFailed { source, .. },
+                InjectError::ProviderFailed {
+                    source: other_source,
+                    ..
+                },
+            ) => source.to_string() == other_source.to_string(),
+            _ => true,
+        };
+        self.kind() == other.kind()
+            && self.type_name() == other.type_name()
+            && self.path() == other.path()
+            && same_source
+    }
 }
 
 impl Display for InjectError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let type_name = self.type_name();
         match self {
-            InjectError::FailedCast => write!(f, "failed cast"),
-            InjectError::MissingProvider => write!(f, "no provider available"),
+            InjectError::FailedCast { .. } => write!(f, "failed cast for `{}`", type_name)?,
+            InjectError::MissingProvider { .. } => {
+                write!(f, "no provider available for `{}`", type_name)?
+            }
+            InjectError::MissingScope { .. } => {
+                write!(f, "no scope entered to resolve `{}`", type_name)?
+            }
+            InjectError::Cycle { cycle, .. } => {
+                write!(f, "dependency cycle detected: {}", cycle.join(" -> "))?
+            }
+            InjectError::ProviderFailed { .. } => write!(f, "provider for `{}` failed", type_name)?,
+            InjectError::DuplicateBinding { .. } => {
+                write!(f, "a provider for `{}` is already installed", type_name)?
+            }
+        }
+        if self.path().len() > 1 {
+            write!(f, " (resolving {})", self.path().join(" -> "))?;
         }
+        Ok(())
     }
 }
 
-impl Error for InjectError {}
+impl Error for InjectError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InjectError::ProviderFailed { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
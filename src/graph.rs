@@ -0,0 +1,384 @@
+//! Introspection of the providers installed into a container
+//!
+//! [`container.graph()`](../struct.Container.html#method.graph) lists the providers installed
+//! into a container and its ancestors, as [`ProviderInfo`](struct.ProviderInfo.html)s, together
+//! with the types they depend on. The [`Graph`](struct.Graph.html) can be exported to
+//! [Graphviz](https://graphviz.org) DOT using [`graph.to_dot()`](struct.Graph.html#method.to_dot),
+//! or to JSON using [`graph.to_json()`](struct.Graph.html#method.to_json).
+//!
+//! The dependencies of a type are declared by the `inject_dependencies` function generated by
+//! [`#[inject]`](../attr.inject.html) and [`#[derive(Inject)]`](../derive.Inject.html), listing the
+//! arguments or fields they resolve. A provider reports them using
+//! [`provider.dependencies()`](../provider/trait.Provider.html#method.dependencies), as
+//! [`singleton!`](../macro.singleton.html) and [`scoped!`](../macro.scoped.html) do.
+//!
+//! Closures, [`async_fn`](../provider/fn.async_fn.html) providers and reference providers report
+//! no dependencies. They can resolve anything from the container they are given, which is only
+//! known once they run, so the graph has no edges from them and
+//! [`graph.unbound()`](struct.Graph.html#method.unbound) misses what they resolve. To have them
+//! listed, install the type using `singleton!` or `scoped!`, or implement
+//! [`Provider`](../provider/trait.Provider.html) and override `dependencies()`.
+//!
+//! # Example
+//!
+//! ```
+//! use ::inject::{container, inject, singleton, Container};
+//!
+//! struct Pool;
+//!
+//! struct Repo;
+//!
+//! impl Repo {
+//!     #[inject(named(url = "db_url"))]
+//!     fn new(pool: &Pool, url: String) -> Self {
+//!         Self
+//!     }
+//! }
+//!
+//! let container = container![
+//!     singleton!(Repo),
+//!     ref Box::new(Pool),
+//!     "db_url" => |_: &Container| Ok(String::from("postgres://")),
+//! ];
+//!
+//! let graph = container.graph();
+//! let repo = &graph.providers()[0];
+//!
+//! assert!(repo.type_name().ends_with("Repo>"));
+//! assert_eq!(2, repo.dependencies().len());
+//! assert!(graph.providers()[2].dependencies().is_empty());
+//! assert!(graph.to_dot().starts_with("digraph inject {"));
+//! ```
+use std::any::type_name;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::key::Key;
+use crate::provider::Provider;
+use crate::Container;
+
+/// A type resolved by an injectable type, optionally qualified by the name of its provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dependency {
+    type_name: &'static str,
+    name: Option<&'static str>,
+}
+
+impl Dependency {
+    /// A dependency on `T`, resolved from the provider installed under `name`, if any.
+    pub fn of<T: ?Sized>(name: Option<&'static str>) -> Self {
+        Self::new(type_name::<T>(), name)
+    }
+
+    /// A dependency on the type named `type_name`. Used for types that cannot be named by
+    /// `std::any::type_name`, like type parameters of a generic constructor.
+    pub fn new(type_name: &'static str, name: Option<&'static str>) -> Self {
+        Self { type_name, name }
+    }
+
+    /// The name of the type depended on.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The name of the provider the type is resolved from, if any.
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+}
+
+/// How a provider was installed into a [`Container`](../struct.Container.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProviderKind {
+    /// Using [`container.install(..)`](../struct.Container.html#method.install).
+    Value,
+    /// Using [`container.install_ref(..)`](../struct.Container.html#method.install_ref).
+    Reference,
+    /// Using [`container.install_async(..)`](../struct.Container.html#method.install_async).
+    Async,
+    /// Using [`container.install_into_set(..)`](../struct.Container.html#method.install_into_set).
+    Set,
+    /// Using [`container.install_into_map(..)`](../struct.Container.html#method.install_into_map).
+    Map,
+    /// Using [`container.decorate(..)`](../struct.Container.html#method.decorate).
+    Decorator,
+}
+
+impl ProviderKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ProviderKind::Value => "value",
+            ProviderKind::Reference => "reference",
+            ProviderKind::Async => "async",
+            ProviderKind::Set => "set",
+            ProviderKind::Map => "map",
+            ProviderKind::Decorator => "decorator",
+        }
+    }
+}
+
+/// How long an instance provided by a provider is reused for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lifetime {
+    /// A new instance is provided every time.
+    Transient,
+    /// The same instance is provided every time, like by [`singleton!`](../macro.singleton.html)
+    /// or a reference provider.
+    Singleton,
+    /// The same instance is provided within a scope, like by [`scoped!`](../macro.scoped.html).
+    Scoped,
+}
+
+impl Lifetime {
+    fn as_str(self) -> &'static str {
+        match self {
+            Lifetime::Transient => "transient",
+            Lifetime::Singleton => "singleton",
+            Lifetime::Scoped => "scoped",
+        }
+    }
+}
+
+/// An installed provider, and the type it provides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderInfo {
+    type_name: &'static str,
+    name: Option<&'static str>,
+    kind: ProviderKind,
+    lifetime: Lifetime,
+    dependencies: Vec<Dependency>,
+}
+
+impl ProviderInfo {
+    /// A provider of `T`, as resolved by its dependents: `&U` for references, `Vec<U>` for sets
+    /// and `HashMap<K, U>` for maps.
+    pub(crate) fn of<T: ?Sized>(name: Option<&'static str>, kind: ProviderKind) -> Self {
+        Self {
+            type_name: type_name::<T>(),
+            name,
+            kind,
+            lifetime: Lifetime::Transient,
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Describes the lifetime and dependencies reported by `provider`.
+    pub(crate) fn provided_by<P: Provider>(self, provider: &P) -> Self {
+        Self {
+            lifetime: provider.lifetime(),
+            dependencies: provider.dependencies(),
+            ..self
+        }
+    }
+
+    pub(crate) fn with_lifetime(self, lifetime: Lifetime) -> Self {
+        Self { lifetime, ..self }
+    }
+
+    /// The name of the provided type.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The name the provider was installed under, if any.
+    pub fn name(&self) -> Option<&'static str> {
+        self.name
+    }
+
+    /// How the provider was installed.
+    pub fn kind(&self) -> ProviderKind {
+        self.kind
+    }
+
+    /// How long the provided instances are reused for, as reported by
+    /// [`provider.lifetime()`](../provider/trait.Provider.html#method.lifetime). References are
+    /// always [`Singleton`](enum.Lifetime.html#variant.Singleton)s.
+    pub fn lifetime(&self) -> Lifetime {
+        self.lifetime
+    }
+
+    /// The types the provider depends on, as reported by
+    /// [`provider.dependencies()`](../provider/trait.Provider.html#method.dependencies). For sets
+    /// and maps, those of every contribution.
+    pub fn dependencies(&self) -> &[Dependency] {
+        &self.dependencies
+    }
+
+    /// Whether this provider provides `dependency`.
+    pub fn provides(&self, dependency: &Dependency) -> bool {
+        self.type_name == dependency.type_name && self.name == dependency.name
+    }
+}
+
+/// The providers installed into a container and its ancestors, see the
+/// [module documentation](index.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph {
+    providers: Vec<ProviderInfo>,
+}
+
+impl Graph {
+    /// The installed providers, outermost ancestor first, in installation order. Shadowed
+    /// providers are left out, and contributions to a set or map, or decorators of a type, are
+    /// listed once.
+    pub fn providers(&self) -> &[ProviderInfo] {
+        &self.providers
+    }
+
+    /// The dependencies of the installed providers which no provider provides. They may still be
+    /// resolved by [`get!`](../macro.get.html), like types with an `#[inject]` constructor.
+    pub fn unbound(&self) -> Vec<Dependency> {
+        let mut seen = HashSet::new();
+        self.providers
+            .iter()
+            .flat_map(|provider| provider.dependencies.iter().copied())
+            .filter(|dependency| !self.providers.iter().any(|p| p.provides(dependency)))
+            .filter(|dependency| seen.insert(*dependency))
+            .collect()
+    }
+
+    /// The graph in Graphviz DOT format. Every provider is a node, with an edge to each of its
+    /// dependencies. Unbound dependencies are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph inject {\n");
+        for (index, provider) in self.providers.iter().enumerate() {
+            let label = label(provider.type_name, provider.name);
+            let _ = writeln!(
+                dot,
+                "    p{} [label=\"{} ({})\"];",
+                index,
+                escape(&label),
+                provider.kind.as_str()
+            );
+        }
+        let unbound = self.unbound();
+        for (index, dependency) in unbound.iter().enumerate() {
+            let label = label(dependency.type_name, dependency.name);
+            let _ = writeln!(
+                dot,
+                "    u{} [label=\"{}\", style=dashed];",
+                index,
+                escape(&label)
+            );
+        }
+        for (index, provider) in self.providers.iter().enumerate() {
+            for dependency in &provider.dependencies {
+                let target = match self.providers.iter().position(|p| p.provides(dependency)) {
+                    Some(target) => format!("p{}", target),
+                    None => format!("u{}", unbound.iter().position(|u| u == dependency).unwrap()),
+                };
+                let _ = writeln!(dot, "    p{} -> {};", index, target);
+            }
+        }
+        dot.push('}');
+        dot.push('\n');
+        dot
+    }
+
+    /// The graph as a JSON object, with a `providers` array listing the `type`, `name`, `kind`,
+    /// `lifetime` and `dependencies` of every provider.
+    pub fn to_json(&self) -> String {
+        let providers: Vec<_> = self
+            .providers
+            .iter()
+            .map(|provider| {
+                let dependencies: Vec<_> = provider
+                    .dependencies
+                    .iter()
+                    .map(|dependency| {
+                        format!(
+                            "{{\"type\":{},\"name\":{}}}",
+                            json_string(dependency.type_name),
+                            json_name(dependency.name)
+                        )
+                    })
+                    .collect();
+                format!(
+                    "{{\"type\":{},\"name\":{},\"kind\":\"{}\",\"lifetime\":\"{}\",\"dependencies\":[{}]}}",
+                    json_string(provider.type_name),
+                    json_name(provider.name),
+                    provider.kind.as_str(),
+                    provider.lifetime.as_str(),
+                    dependencies.join(",")
+                )
+            })
+            .collect();
+        format!("{{\"providers\":[{}]}}", providers.join(","))
+    }
+}
+
+impl Container {
+    /// The providers installed into this container and its ancestors, and their dependencies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::graph::ProviderKind;
+    /// use inject::{container, Container};
+    ///
+    /// let container = container![
+    ///     |_: &Container| Ok(5432u16),
+    ///     "db_url" => |_: &Container| Ok(String::from("postgres://")),
+    /// ];
+    ///
+    /// let graph = container.graph();
+    ///
+    /// assert_eq!("u16", graph.providers()[0].type_name());
+    /// assert_eq!(Some("db_url"), graph.providers()[1].name());
+    /// assert_eq!(ProviderKind::Value, graph.providers()[1].kind());
+    /// ```
+    pub fn graph(&self) -> Graph {
+        let mut lineage: Vec<_> = self.lineage().collect();
+        lineage.reverse();
+        let mut providers: Vec<(Key, ProviderInfo)> = Vec::new();
+        for entry in lineage
+            .into_iter()
+            .flat_map(|container| container.bindings.iter())
+        {
+            match providers.iter_mut().find(|(key, _)| *key == entry.key) {
+                Some((Key::Set(_), info))
+                | Some((Key::Map(..), info))
+                | Some((Key::Decorator(_), info)) => info
+                    .dependencies
+                    .extend(entry.info.dependencies.iter().copied()),
+                Some((_, info)) => *info = entry.info.clone(),
+                None => providers.push((entry.key, entry.info.clone())),
+            }
+        }
+        Graph {
+            providers: providers.into_iter().map(|(_, info)| info).collect(),
+        }
+    }
+}
+
+fn label(type_name: &str, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{} \"{}\"", type_name, name),
+        None => type_name.to_string(),
+    }
+}
+
+/// Escapes `value` for a double quoted DOT or JSON string.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            character if character.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", character as u32);
+            }
+            character => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+fn json_string(value: &str) -> String {
+    format!("\"{}\"", escape(value))
+}
+
+fn json_name(name: Option<&str>) -> String {
+    name.map_or_else(|| String::from("null"), json_string)
+}
@@ -5,12 +5,26 @@
 //!
 //! Any type which implements `Inject` and `Default` trivially implements `InjectExt`, which means
 //! that when no provider is present, and [`get!`](../macro.get.html) is used to resolve a type,
-//! `InjectExt::inject(..)` will be invoked.
+//! `InjectExt::inject(..)` will be invoked. Likewise, `InjectExt::inject_async(..)` will be invoked
+//! when resolving asynchronously.
 //!
 use std::any::TypeId;
+use std::future::Future;
+
+use crate::graph::Dependency;
 
 /// Marker trait for an injectable type.
-pub trait Inject: 'static {}
+pub trait Inject: 'static {
+    /// The dependencies of the type, see [`graph`](../graph/index.html). Empty, unless shadowed
+    /// by the associated function generated by [`#[inject]`](../attr.inject.html) or
+    /// [`#[derive(Inject)]`](../derive.Inject.html).
+    fn inject_dependencies() -> Vec<Dependency>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+}
 
 /// Trait to blanket implement an associated `inject` method for all types implementing `Default`,
 /// enabling ergonomic [`get!`](../macro.get.html) and [`call!`](../macro.call.html) usages.
@@ -19,6 +33,15 @@ pub trait InjectExt: Inject + Default {
     fn inject(_container: &crate::Container) -> Result<Self, crate::InjectError> {
         Ok(Self::default())
     }
+
+    fn inject_async(
+        _container: &crate::Container,
+    ) -> impl Future<Output = Result<Self, crate::InjectError>> + Send
+    where
+        Self: Send,
+    {
+        async { Ok(Self::default()) }
+    }
 }
 
 pub fn id<T: 'static>() -> TypeId {
@@ -44,6 +67,7 @@ mod tests {
         val: isize,
     }
 
+    #[allow(dead_code)]
     trait FakeTrait: Sized + Send {}
 
     impl FakeTrait for FakeImpl {}
@@ -0,0 +1,143 @@
+use crate::graph::ProviderInfo;
+use crate::key::Key;
+use crate::Container;
+
+impl Container {
+    /// Whether a [`Provider`](provider/trait.Provider.html) of `T` is installed into this
+    /// container or one of its ancestors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{container, Container};
+    ///
+    /// let container = container![|_: &Container| Ok(5usize), ref Box::new(2isize)];
+    ///
+    /// assert!(container.has::<usize>());
+    /// assert!(!container.has::<isize>());
+    /// assert!(container.has_ref::<isize>());
+    /// ```
+    pub fn has<T: 'static>(&self) -> bool {
+        self.binding(Key::of::<T>(None)).is_some()
+    }
+
+    /// Whether a [`RefProvider`](provider/trait.RefProvider.html) of `T` is installed into this
+    /// container or one of its ancestors.
+    pub fn has_ref<T: ?Sized + 'static>(&self) -> bool {
+        self.binding(Key::of::<&T>(None)).is_some()
+    }
+
+    /// Whether a [`Provider`](provider/trait.Provider.html) of `T` is installed under `name` into
+    /// this container or one of its ancestors.
+    pub fn has_named<T: 'static>(&self, name: &'static str) -> bool {
+        self.binding(Key::of::<T>(Some(name))).is_some()
+    }
+
+    /// Whether a [`RefProvider`](provider/trait.RefProvider.html) of `T` is installed under `name`
+    /// into this container or one of its ancestors.
+    pub fn has_ref_named<T: ?Sized + 'static>(&self, name: &'static str) -> bool {
+        self.binding(Key::of::<&T>(Some(name))).is_some()
+    }
+
+    /// Remove the [`Provider`](provider/trait.Provider.html) of `T` installed into this
+    /// container, returning whether there was one.
+    ///
+    /// The removed provider is dropped. Providers installed into the ancestors of this container
+    /// are not removed, so `T` is resolved using them afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{container, get, Container};
+    ///
+    /// let mut container = container![|_: &Container| Ok(5usize)];
+    ///
+    /// assert!(container.remove::<usize>());
+    /// assert!(!container.has::<usize>());
+    /// assert!(get!(&container, usize, create: false).is_err());
+    /// ```
+    pub fn remove<T: 'static>(&mut self) -> bool {
+        self.remove_key(Key::of::<T>(None))
+    }
+
+    /// Remove the [`RefProvider`](provider/trait.RefProvider.html) of `T` installed into this
+    /// container, returning whether there was one. References handed out by the removed provider
+    /// stay valid, as it is then only dropped together with the container.
+    pub fn remove_ref<T: ?Sized + 'static>(&mut self) -> bool {
+        self.remove_key(Key::of::<&T>(None))
+    }
+
+    /// Like [`container.remove()`](struct.Container.html#method.remove), for the provider
+    /// installed under `name`.
+    pub fn remove_named<T: 'static>(&mut self, name: &'static str) -> bool {
+        self.remove_key(Key::of::<T>(Some(name)))
+    }
+
+    /// Like [`container.remove_ref()`](struct.Container.html#method.remove_ref), for the provider
+    /// installed under `name`.
+    pub fn remove_ref_named<T: ?Sized + 'static>(&mut self, name: &'static str) -> bool {
+        self.remove_key(Key::of::<&T>(Some(name)))
+    }
+
+    /// The providers installed into this container and its ancestors, outermost ancestor first,
+    /// in installation order. Shadowed providers are left out, while every contribution to a set
+    /// or map, and every decorator, is listed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::graph::{Lifetime, ProviderKind};
+    /// use inject::{container, singleton, Container};
+    ///
+    /// #[derive(Default)]
+    /// struct Pool;
+    ///
+    /// let container = container![singleton!(Pool), ref "port" => Box::new(5432u16)];
+    ///
+    /// for provider in container.providers() {
+    ///     println!("{} {:?}", provider.type_name(), provider.lifetime());
+    /// }
+    ///
+    /// let port = container.providers().nth(1).unwrap();
+    /// assert_eq!(2, container.len());
+    /// assert_eq!(Some("port"), port.name());
+    /// assert_eq!(ProviderKind::Reference, port.kind());
+    /// assert_eq!(Lifetime::Singleton, port.lifetime());
+    /// ```
+    pub fn providers(&self) -> impl Iterator<Item = ProviderInfo> {
+        let mut lineage: Vec<_> = self.lineage().collect();
+        lineage.reverse();
+        let entries: Vec<_> = lineage
+            .into_iter()
+            .flat_map(|container| container.bindings.iter())
+            .collect();
+        let shadowed: Vec<_> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| match entry.key {
+                Key::Set(_) | Key::Map(..) | Key::Decorator(_) => false,
+                key => entries[index + 1..].iter().any(|later| later.key == key),
+            })
+            .collect();
+        entries
+            .into_iter()
+            .zip(shadowed)
+            .filter(|(_, shadowed)| !shadowed)
+            .map(|(entry, _)| entry.info)
+    }
+
+    /// The number of providers listed by
+    /// [`container.providers()`](struct.Container.html#method.providers).
+    pub fn len(&self) -> usize {
+        self.providers().count()
+    }
+
+    /// Whether no provider is installed into this container or its ancestors.
+    pub fn is_empty(&self) -> bool {
+        self.providers().next().is_none()
+    }
+
+    fn remove_key(&mut self, key: Key) -> bool {
+        self.bindings.remove(key)
+    }
+}
@@ -0,0 +1,41 @@
+use std::any::TypeId;
+
+use crate::inject;
+
+/// Identifies a binding within a [`Container`](../struct.Container.html).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Key {
+    /// A provided type, optionally qualified by a name. Installing a provider for the same key
+    /// replaces the previous one.
+    Single(TypeId, Option<&'static str>),
+    /// An asynchronously provided type, optionally qualified by a name.
+    Async(TypeId, Option<&'static str>),
+    /// A contribution to the set binding of a type.
+    Set(TypeId),
+    /// A contribution to the map binding of a type, with the type id of its keys.
+    Map(TypeId, TypeId),
+    /// A decorator of a provided type. Decorators accumulate like contributions.
+    Decorator(TypeId),
+}
+
+impl Key {
+    pub fn of<T: ?Sized + 'static>(name: Option<&'static str>) -> Self {
+        Key::Single(TypeId::of::<T>(), name)
+    }
+
+    pub fn of_async<T: 'static>(name: Option<&'static str>) -> Self {
+        Key::Async(TypeId::of::<T>(), name)
+    }
+
+    pub fn set<T: 'static>() -> Self {
+        Key::Set(inject::id::<T>())
+    }
+
+    pub fn map<K: 'static, T: 'static>() -> Self {
+        Key::Map(inject::id::<K>(), inject::id::<T>())
+    }
+
+    pub fn decorator<T: 'static>() -> Self {
+        Key::Decorator(TypeId::of::<T>())
+    }
+}
@@ -0,0 +1,148 @@
+//! Deferred resolution of dependencies
+//!
+//! A [`Lazy<T>`](struct.Lazy.html) resolves `T` the first time it is dereferenced, and a
+//! [`Factory<T>`](struct.Factory.html) resolves a new `T` every time it is called. Both resolve
+//! using the container they were resolved from, so a constructor taking them does not build their
+//! dependencies until they are used.
+//!
+//! [`get!`](../macro.get.html) resolves `Lazy<T>`, `Factory<T>` and
+//! `Box<dyn Fn() -> Result<T, InjectError>>` without installed providers, resolving `T` as `get!`
+//! would when used. Any `create` or `named` options apply to `T`.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//! use std::sync::Arc;
+//!
+//! use ::inject::{container, get, inject, Container, Factory, Lazy};
+//!
+//! struct Report {
+//!     summary: Lazy<String>,
+//!     ids: Factory<usize>,
+//! }
+//!
+//! impl Report {
+//!     #[inject]
+//!     fn new(summary: Lazy<String>, ids: Factory<usize>) -> Self {
+//!         Self { summary, ids }
+//!     }
+//! }
+//!
+//! let next_id = Arc::new(AtomicUsize::new(0));
+//! let container = container![
+//!     |_: &Container| Ok(String::from("expensive")),
+//!     move |_: &Container| Ok(next_id.fetch_add(1, Ordering::SeqCst)),
+//! ];
+//!
+//! let report = get!(&container, Report).unwrap();
+//!
+//! assert_eq!("expensive", report.summary.as_str());
+//! assert_eq!(Ok(0), report.ids.create());
+//! assert_eq!(Ok(1), report.ids.create());
+//! ```
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Arc, OnceLock};
+
+use crate::Container;
+use crate::InjectError;
+
+/// Resolves a `T` using a container.
+type Resolve<T> = dyn Fn(&Container) -> Result<T, InjectError> + Send + Sync;
+
+/// A `T` resolved on first use, and cached afterwards.
+pub struct Lazy<T> {
+    container: Container,
+    resolve: Box<Resolve<T>>,
+    instance: OnceLock<T>,
+}
+
+impl<T> Lazy<T> {
+    /// Create a `Lazy<T>`, resolving `T` using `resolve` with a container sharing the providers
+    /// of `container`.
+    pub fn new(
+        container: &Container,
+        resolve: impl Fn(&Container) -> Result<T, InjectError> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            container: container.share(),
+            resolve: Box::new(resolve),
+            instance: OnceLock::new(),
+        }
+    }
+
+    /// The resolved instance, resolving it if this is the first use. A failed resolution is
+    /// retried on the next use.
+    pub fn get(&self) -> Result<&T, InjectError> {
+        if let Some(instance) = self.instance.get() {
+            return Ok(instance);
+        }
+        let instance = (self.resolve)(&self.container)?;
+        Ok(self.instance.get_or_init(|| instance))
+    }
+}
+
+impl<T> Deref for Lazy<T> {
+    type Target = T;
+
+    /// Dereferences the resolved instance.
+    ///
+    /// # Panics
+    ///
+    /// If `T` cannot be resolved. Use [`lazy.get()`](#method.get) to handle the error instead.
+    fn deref(&self) -> &T {
+        match self.get() {
+            Ok(instance) => instance,
+            Err(err) => panic!("failed to resolve lazy dependency: {}", err),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lazy")
+            .field("instance", &self.instance.get())
+            .finish()
+    }
+}
+
+/// Resolves a new `T` every time it is called.
+pub struct Factory<T> {
+    container: Container,
+    resolve: Arc<Resolve<T>>,
+}
+
+impl<T> Factory<T> {
+    /// Create a `Factory<T>`, resolving `T` using `resolve` with a container sharing the providers
+    /// of `container`.
+    pub fn new(
+        container: &Container,
+        resolve: impl Fn(&Container) -> Result<T, InjectError> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            container: container.share(),
+            resolve: Arc::new(resolve),
+        }
+    }
+
+    /// Resolve a new instance.
+    pub fn create(&self) -> Result<T, InjectError> {
+        (self.resolve)(&self.container)
+    }
+}
+
+impl<T> Clone for Factory<T> {
+    fn clone(&self) -> Self {
+        Self {
+            container: self.container.share(),
+            resolve: Arc::clone(&self.resolve),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Factory<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Factory").finish_non_exhaustive()
+    }
+}
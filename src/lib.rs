@@ -17,6 +17,8 @@
 //!
 //! [`#[inject]`](attr.inject.html) generates code to enable the above two macros.
 //!
+//! [`#[derive(Inject)]`](derive.Inject.html) does the same for a struct, by injecting its fields.
+//!
 //! # Example
 //!
 //! ```
@@ -54,19 +56,20 @@
 //! resolve the `&Connection` dependency.
 
 use std::any::Any;
-use std::any::TypeId;
-use std::collections::HashMap;
+use std::borrow::Cow;
 use std::sync::Arc;
 
 /// Call a function with dependency resolution for its arguments
 ///
 /// `call!(..) accepts 2-3 arguments.
 /// 1. The first argument can be any expression, and should return a
-/// reference to a [`Container`](struct.Container.html) instance.
-/// 2. The second argument should be the name of a function that has been annotated using the
-/// [`#[inject]`](attr.inject.html) attribute.
+///    reference to a [`Container`](struct.Container.html) instance.
+/// 2. The second argument should be the name or path of a function that has been annotated using
+///    the [`#[inject]`](attr.inject.html) attribute, like `handlers::create_user`, or an
+///    annotated `&self` method together with its receiver, like `service.handle`.
 /// 3. Optionally, a sequence of keyword-value-arguments (kwargs) can be supplied on the form
-/// `kwargs = {arg1: expr1, arg2: expr2}`, for a method with arguments
+///    `kwargs = {arg1: expr1, arg2: expr2}`, for a method with arguments. A kwarg `arg` is short for
+///    `arg: arg`.
 ///
 /// # Examples
 ///
@@ -135,6 +138,64 @@ use std::sync::Arc;
 /// Under the hood, if an arg is not provided a corresponding kwarg, the
 /// [`get!`](macro.get.html) macro is used to evaluate the argument.
 ///
+/// Functions which are not annotated using [`#[inject]`](attr.inject.html) can not be called.
+///
+/// ```compile_fail
+/// use ::inject::{call, container};
+///
+/// fn not_injectable(a: isize) -> isize {
+///     a
+/// }
+///
+/// let container = container![];
+///
+/// call!(&container, not_injectable);
+/// ```
+///
+/// A method is called on its receiver, injecting its remaining arguments.
+///
+/// ```
+/// use ::inject::{call, container, inject};
+///
+/// struct Request(&'static str);
+///
+/// struct Service;
+///
+/// impl Service {
+///     #[inject(no_inject(request))]
+///     fn handle(&self, retries: usize, request: Request) -> String {
+///         format!("{} (retries: {})", request.0, retries)
+///     }
+/// }
+///
+/// let container = container![|_: &_| Ok(3usize)];
+/// let service = Service;
+/// let request = Request("GET /");
+///
+/// let result = call!(&container, service.handle, kwargs = { request }).unwrap();
+///
+/// assert_eq!(result, "GET / (retries: 3)");
+/// ```
+///
+/// A generic function is called by supplying its type parameters with a turbofish, as in
+/// `call!(&container, function::<Postgres>)`.
+///
+/// An `async` function is called with `call!(async &container, function)`, which returns a future
+/// resolving each argument using `get!(async ..)`.
+///
+/// ```
+/// use ::inject::{call, container, inject};
+///
+/// #[inject]
+/// async fn foo(a: isize) -> isize {
+///     a + 1
+/// }
+///
+/// let container = container![];
+///
+/// assert_eq!(Ok(1), pollster::block_on(call!(async &container, foo)));
+/// ```
+///
 pub use inject_macro::call;
 
 /// Create a container with providers
@@ -142,18 +203,94 @@ pub use inject_macro::call;
 /// `container![..]` accepts any number of arguments, each which is expected to implement one of the
 /// [provider traits](provider.mod.html)
 ///
+/// A provider prefixed by `ref` is installed as a [`RefProvider`](provider/trait.RefProvider.html),
+/// and a provider prefixed by a string literal and `=>` is installed under that name.
+///
+/// ```
+/// use inject::{container, get, Container};
+///
+/// let container = container![
+///     "db_url" => |_: &Container| Ok(String::from("postgres://")),
+///     ref "port" => Box::new(5432u16),
+/// ];
+///
+/// assert_eq!(Ok("postgres://".into()), get!(&container, String, named: "db_url"));
+/// assert_eq!(Ok(&5432), get!(&container, &u16, named: "port"));
+/// ```
+///
+/// An argument like `dyn Trait => Impl` binds the trait object to `Impl`, resolved using
+/// [`get!`](macro.get.html), as with [`container.bind_with(..)`](struct.Container.html#method.bind_with).
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use inject::{container, get};
+///
+/// trait Greeter: Send + Sync {}
+///
+/// #[derive(Default)]
+/// struct Hello;
+///
+/// impl Greeter for Hello {}
+///
+/// let container = container![dyn Greeter => Hello];
+///
+/// assert!(get!(&container, Arc<dyn Greeter>).is_ok());
+/// ```
+///
+/// A provider prefixed by `async` is installed as an
+/// [`AsyncProvider`](provider/trait.AsyncProvider.html), see [`get!`](macro.get.html).
+///
+/// An argument prefixed by `module` installs the providers of a [`Module`](module/trait.Module.html),
+/// as with [`container.install_module(..)`](struct.Container.html#method.install_module).
+///
+/// ```
+/// use inject::{container, get, Container};
+///
+/// let container = container![
+///     module |binder: &mut Container| binder.install(|_: &Container| Ok(5usize)),
+///     |_: &Container| Ok(2isize),
+/// ];
+///
+/// assert_eq!(Ok(5), get!(&container, usize));
+/// ```
+///
+/// An argument prefixed by `override` is installed after every other argument, replacing the
+/// providers they install for the same type, like fakes replacing those of a production module.
+///
+/// ```
+/// use inject::{container, get, Container};
+///
+/// let production = |binder: &mut Container| {
+///     binder.install(|_: &Container| Ok(String::from("postgres://")));
+///     binder.install(|_: &Container| Ok(5432u16));
+/// };
+///
+/// let container = container![
+///     override |_: &Container| Ok(String::from("sqlite::memory:")),
+///     module production,
+/// ];
+///
+/// assert_eq!(Ok("sqlite::memory:".into()), get!(&container, String));
+/// assert_eq!(Ok(5432), get!(&container, u16));
+/// ```
+///
 pub use inject_macro::container;
 
 /// Resolve a dependency from a container
 ///
-/// `get!(..)` accepts 2-3 arguments.
+/// `get!(..)` accepts 2-5 arguments.
 /// 1. The first argument can be any expression, and should return a
-/// reference to a [`Container`](struct.Container.html) instance.
+///    reference to a [`Container`](struct.Container.html) instance.
 /// 2. The second argument should be
-/// a type which we want to resolve, optionally prepended by an '`&`' to indicate that we
-/// want a reference.
-/// 3. Lastly, the `create: (true|false)` key-value can be supplied to indicate
-/// that we only want to use a `Provider` for the type, NOT the associated `inject` method.
+///    a type which we want to resolve, optionally prepended by an '`&`' to indicate that we
+///    want a reference.
+/// 3. The `create: (true|false)` key-value can be supplied to indicate
+///    that we only want to use a `Provider` for the type, NOT the associated `inject` method.
+/// 4. Lastly, the `named: "name"` key-value can be supplied to resolve the type using the
+///    provider installed under that name. Named types are only ever resolved using a provider.
+/// 5. The `kwargs = {arg1: expr1, arg2: expr2}` key-value can be supplied to construct the type
+///    using its [`#[inject]`](attr.inject.html) constructor, as [`call!`](macro.call.html) would.
 ///
 /// # Example
 ///
@@ -189,12 +326,75 @@ pub use inject_macro::container;
 /// The `create: (true|false)` key-value only holds meaning for value types. New references cannot be
 /// created by the macro, as their corresponding instance is dropped on return.
 ///
+/// A `Vec<T>` without an installed provider of its own resolves every provider contributed
+/// for `T` using [`container.install_into_set(..)`](struct.Container.html#method.install_into_set).
+/// Likewise, a `HashMap<K, T>` resolves every provider contributed for `T` using
+/// [`container.install_into_map(..)`](struct.Container.html#method.install_into_map), and a
+/// `HashMap<K, Box<dyn Fn() -> Result<T, InjectError>>>` defers invoking them until called.
+///
+/// An `Option<&T>` resolves to `None` if no provider is installed for `T`, using
+/// [`container.try_get_ref()`](struct.Container.html#method.try_get_ref). An `Option<T>` creates
+/// `T` like `get!` does, and resolves to `None` if that fails for lack of a provider, using
+/// [`container.try_get_or_else(..)`](struct.Container.html#method.try_get_or_else). Other errors
+/// are still returned.
+///
+/// Similarly, a [`Lazy<T>`](lazy/struct.Lazy.html), [`Factory<T>`](lazy/struct.Factory.html) or
+/// `Box<dyn Fn() -> Result<T, InjectError>>` without an installed provider defers resolving `T`
+/// until used.
+///
+/// Kwargs construct a value-type using its `#[inject]` constructor, supplying the given arguments
+/// and resolving the others. Installed providers for the type itself are bypassed, so kwargs
+/// cannot be combined with `&`, `named` or `create: false`.
+///
+/// ```
+/// use ::inject::{container, get, inject, Container};
+///
+/// struct Service {
+///     url: String,
+///     timeout: u64,
+/// }
+///
+/// impl Service {
+///     #[inject]
+///     fn new(url: String, timeout: u64) -> Self {
+///         Self { url, timeout }
+///     }
+/// }
+///
+/// let container = container![|_: &Container| Ok(String::from("postgres://"))];
+///
+/// let service = get!(&container, Service, kwargs = { timeout: 5 }).unwrap();
+///
+/// assert_eq!("postgres://", service.url);
+/// assert_eq!(5, service.timeout);
+/// ```
+///
+/// Prefixing the container with `async` returns a future instead, which resolves the type using
+/// an [`AsyncProvider`](provider/trait.AsyncProvider.html) when one is installed, and otherwise
+/// falls back to `inject_async`, which every [`#[inject]`](attr.inject.html) constructor
+/// generates.
+///
+/// ```
+/// use inject::{container, get, Container};
+/// use inject::provider::async_fn;
+///
+/// let container = container![
+///     async async_fn(|_: &Container| Box::pin(async { Ok(String::from("postgres://")) })),
+/// ];
+///
+/// assert_eq!(Ok("postgres://".into()), pollster::block_on(get!(async &container, String)));
+///
+/// // Synchronous resolution ignores asynchronous providers.
+/// assert!(get!(&container, String, create: false).is_err());
+/// ```
+///
 pub use inject_macro::get;
 
 /// Generate functionality for a function/constructor to be injectable
 ///
-/// [`#[inject]`](attr.inject.html) accepts two positions:
-/// in a "free" function, or a struct impl method that returns `Self`.
+/// [`#[inject]`](attr.inject.html) accepts three positions:
+/// in a "free" function, a struct impl method that returns `Self`, or a `&self` method, called
+/// using [`call!`](macro.call.html).
 ///
 /// # Examples
 ///
@@ -218,8 +418,10 @@ pub use inject_macro::get;
 ///
 /// ```
 ///
-/// When in free function position, a set of macro_rules macros are generated (and hidden), which
-/// enables injection and kwarg-style resolution of the function arguments.
+/// When in free function position, a hidden `__inject_<name>` struct is generated next to the
+/// function, which enables injection and kwarg-style resolution of the function arguments. As it
+/// shares the visibility and module of the function, the function can be called by path from other
+/// modules and crates.
 ///
 /// ```
 /// use ::inject::{call, Container, container, inject};
@@ -237,23 +439,140 @@ pub use inject_macro::get;
 /// assert_eq!(result, 4);
 ///
 /// // Use kwargs to provide a value for one of the args of the function
-/// // By using macros generated by the #[inject] attribute.
+/// // By using the struct generated by the #[inject] attribute.
 /// let result = call!(&container, injectable, kwargs = { b: 12 }).unwrap();
 /// assert_eq!(result, 14);
 ///
 /// ```
+///
+/// Arguments can be qualified with `named(arg = "name")`, resolving them from the provider
+/// installed under that name.
+///
+/// ```
+/// use ::inject::{container, get, inject, Container};
+///
+/// struct Urls(String, String);
+///
+/// impl Urls {
+///     #[inject(named(db = "db_url", cache = "cache_url"))]
+///     fn new(db: String, cache: String) -> Self {
+///         Self(db, cache)
+///     }
+/// }
+///
+/// let container = container![
+///     "db_url" => |_: &Container| Ok(String::from("postgres://")),
+///     "cache_url" => |_: &Container| Ok(String::from("redis://")),
+/// ];
+///
+/// let urls = get!(&container, Urls).unwrap();
+/// assert_eq!((urls.0.as_str(), urls.1.as_str()), ("postgres://", "redis://"));
+/// ```
+///
+/// Generic parameters are carried over to the generated `inject` method and macros. Arguments whose
+/// type is a type parameter are only resolved using installed providers.
+///
+/// ```
+/// use ::inject::{call, container, inject, Container};
+///
+/// trait Repo {
+///     fn name(&self) -> &'static str;
+/// }
+///
+/// struct Postgres;
+///
+/// impl Repo for Postgres {
+///     fn name(&self) -> &'static str {
+///         "postgres"
+///     }
+/// }
+///
+/// #[inject]
+/// fn repo_name<R: Repo>(repo: &R) -> &'static str {
+///     repo.name()
+/// }
+///
+/// let container = container![ref Box::new(Postgres)];
+///
+/// assert_eq!(Ok("postgres"), call!(&container, repo_name::<Postgres>));
+/// ```
+///
+/// Constructors also get an associated `inject_async` method, resolving their arguments using
+/// `get!(async ..)`. An `async` constructor only gets `inject_async`. They get an associated
+/// `inject_dependencies` function as well, listing their arguments for
+/// [`container.graph()`](struct.Container.html#method.graph).
+///
+/// A constructor annotated with `#[inject(post_construct = method)]` calls `method` on every
+/// instance it constructs, before handing it out, see [`lifecycle`](lifecycle/index.html).
 pub use inject_macro::inject;
 
-pub use error::InjectError;
-pub use provider::{Provider, RefProvider};
+/// Derive associated `inject` and `inject_async` functions for a struct, resolving each of its
+/// fields
+///
+/// Every field is resolved as if it were an argument of an [`#[inject]`](attr.inject.html)
+/// constructor, and listed by the derived `inject_dependencies`. Fields can be annotated with
+/// `#[inject(..)]` options equivalent to its arguments: `default` or `default = expr`,
+/// `no_inject` and `named = "name"`. Like the type parameters of a constructor, fields whose
+/// type is a type parameter of the struct are only resolved using providers.
+///
+/// ```
+/// use ::inject::{container, get, Container, Inject};
+///
+/// #[derive(Inject)]
+/// struct Config {
+///     #[inject(named = "db_url")]
+///     db_url: String,
+///     #[inject(default = 5432, no_inject)]
+///     port: u16,
+///     #[inject(default, no_inject)]
+///     verbose: bool,
+/// }
+///
+/// let container = container![
+///     "db_url" => |_: &Container| Ok(String::from("postgres://")),
+/// ];
+///
+/// let config = get!(&container, Config).unwrap();
+/// assert_eq!(config.db_url, "postgres://");
+/// assert_eq!(config.port, 5432);
+/// assert!(!config.verbose);
+/// ```
+pub use inject_macro::Inject;
+
+pub use error::{InjectError, InjectErrorKind};
+pub use lazy::{Factory, Lazy};
+pub use lifecycle::Disposable;
+pub use provider::{AsyncProvider, Provider, RefProvider};
 
 pub use crate::inject::{Inject, InjectExt};
 
+use crate::bindings::{Bindings, Entry};
+use crate::graph::{Lifetime, ProviderInfo, ProviderKind};
+use crate::key::Key;
+use crate::resolution::Resolving;
+use crate::scope::Scope;
+
+mod asynchronous;
+pub mod binding;
+mod bindings;
+mod decoration;
 pub mod error;
+pub mod graph;
 pub mod inject;
+mod introspection;
+mod key;
+pub mod lazy;
+pub mod lifecycle;
 pub mod module;
+mod multibinding;
+mod optional;
+mod overrides;
 pub mod provider;
 pub mod providers;
+mod resolution;
+pub mod scope;
+mod strict;
+mod validation;
 
 /// Contains providers for resolvable types.
 ///
@@ -283,9 +602,32 @@ pub mod providers;
 /// assert_eq!(2i32, container.get().unwrap());
 ///
 /// ```
-#[derive(Clone, Debug, Default)]
+///
+/// `Container` is `Send + Sync`, and therefore requires the providers installed into it to be
+/// `Send + Sync` as well. A container can thus be shared between threads using an `Arc`.
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// use inject::{container, get};
+///
+/// let container = Arc::new(container![|_: &_| Ok(5usize)]);
+///
+/// let worker = {
+///     let container = Arc::clone(&container);
+///     thread::spawn(move || get!(&container, usize))
+/// };
+///
+/// assert_eq!(Ok(5), worker.join().unwrap());
+/// ```
+#[derive(Debug, Default)]
 pub struct Container {
-    providers: HashMap<TypeId, Arc<dyn Any>>,
+    bindings: Arc<Bindings>,
+    parent: Option<Arc<Container>>,
+    scope: Option<Arc<Scope>>,
+    strict: bool,
+    overriding: bool,
 }
 
 impl Container {
@@ -295,68 +637,387 @@ impl Container {
         Self::default()
     }
 
-    /// Install a [`Provider`](provider/trait.Provider.html) into this `Container`
-    pub fn install<T: Inject, P: 'static + Provider<ProvidedType = T>>(&mut self, provider: P) {
-        self.providers
-            .insert(provider.id(), Arc::new(Self::box_provider(provider)));
+    /// Install a [`Provider`](provider/trait.Provider.html) into this `Container`, replacing the
+    /// provider previously installed for its type, if any.
+    ///
+    /// # Panics
+    ///
+    /// If the container is [strict](struct.Container.html#method.strict) and a provider is
+    /// already installed for the type.
+    pub fn install<T: Inject, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        &mut self,
+        provider: P,
+    ) {
+        self.push(Self::value_entry(None, provider));
+    }
+
+    /// Install a [`Provider`](provider/trait.Provider.html) into this `Container`, qualified by
+    /// `name`. Named providers live alongside the unnamed provider of the same type, and are
+    /// only resolved through [`container.get_named(..)`](struct.Container.html#method.get_named).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{Container, get};
+    ///
+    /// let mut container = Container::new();
+    /// container.install_named("db_url", |_: &Container| Ok(String::from("postgres://")));
+    /// container.install_named("cache_url", |_: &Container| Ok(String::from("redis://")));
+    ///
+    /// assert_eq!(Ok("postgres://".into()), get!(&container, String, named: "db_url"));
+    /// assert_eq!(Ok("redis://".into()), get!(&container, String, named: "cache_url"));
+    /// ```
+    pub fn install_named<T: Inject, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        &mut self,
+        name: &'static str,
+        provider: P,
+    ) {
+        self.push(Self::value_entry(Some(name), provider));
     }
 
     /// Install a [`RefProvider`](provider/trait.RefProvider.html) into this `Container`
-    pub fn install_ref<T: Inject, P: 'static + RefProvider<ProvidedRef = T>>(
+    pub fn install_ref<
+        T: ?Sized + 'static,
+        P: 'static + RefProvider<ProvidedRef = T> + Send + Sync,
+    >(
         &mut self,
         provider: P,
     ) {
-        self.providers
-            .insert(provider.id(), Arc::new(Self::box_ref_provider(provider)));
+        self.push(Self::ref_entry(None, provider));
+    }
+
+    /// Install a [`RefProvider`](provider/trait.RefProvider.html) into this `Container`, qualified
+    /// by `name`.
+    pub fn install_ref_named<
+        T: ?Sized + 'static,
+        P: 'static + RefProvider<ProvidedRef = T> + Send + Sync,
+    >(
+        &mut self,
+        name: &'static str,
+        provider: P,
+    ) {
+        self.push(Self::ref_entry(Some(name), provider));
     }
 
     /// Resolve a value-type from the installed [`Provider`](provider/trait.Provider.html)s.
     pub fn get<T: Inject>(&self) -> Result<T, InjectError> {
-        let provider = self
-            .providers
-            .get(&inject::id::<T>())
-            .ok_or_else(|| InjectError::MissingProvider)?
-            .downcast_ref::<Box<dyn Provider<ProvidedType = T>>>()
-            .ok_or_else(|| InjectError::FailedCast)?;
-        provider.provide(self)
+        let _resolving = Resolving::enter::<T>(None, self)?;
+        let (provider, container) = self.provider::<T>(None)?;
+        self.provide(&**provider, container)
+    }
+
+    /// Resolve a value-type like [`container.get()`](struct.Container.html#method.get), but
+    /// create it using `fallback` if no [`Provider`](provider/trait.Provider.html) is installed
+    /// for it. Errors raised by `fallback` are reported as occurring while resolving `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{Container, InjectError};
+    ///
+    /// let container = Container::new();
+    ///
+    /// assert_eq!(Ok(5usize), container.get_or_else(|_| Ok(5)));
+    /// ```
+    pub fn get_or_else<T: Inject>(
+        &self,
+        fallback: impl FnOnce(&Container) -> Result<T, InjectError>,
+    ) -> Result<T, InjectError> {
+        let _resolving = Resolving::enter::<T>(None, self)?;
+        match self.provider::<T>(None) {
+            Ok((provider, container)) => self.provide(&**provider, container),
+            Err(InjectError::MissingProvider { .. }) => fallback(self),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Resolve a value-type from the [`Provider`](provider/trait.Provider.html) installed under
+    /// `name`.
+    pub fn get_named<T: Inject>(&self, name: &'static str) -> Result<T, InjectError> {
+        let _resolving = Resolving::enter::<T>(Some(name), self)?;
+        let (provider, container) = self.provider::<T>(Some(name))?;
+        self.provide(&**provider, container)
     }
 
     /// Resolve a reference-type from the installed [`RefProvider`](provider/trait.RefProvider.html)s.
-    pub fn get_ref<T: 'static>(&self) -> Result<&T, InjectError> {
-        let provider = self
-            .providers
-            .get(&inject::id::<&T>())
-            .ok_or_else(|| InjectError::MissingProvider)?
-            .downcast_ref::<Box<dyn RefProvider<ProvidedRef = T>>>()
-            .ok_or_else(|| InjectError::FailedCast)?;
-        provider.provide(self)
+    pub fn get_ref<T: ?Sized + 'static>(&self) -> Result<&T, InjectError> {
+        let _resolving = Resolving::enter::<&T>(None, self)?;
+        let (provider, container) = self.ref_provider::<T>(None)?;
+        provider.provide(container)
+    }
+
+    /// Resolve a reference-type from the [`RefProvider`](provider/trait.RefProvider.html)
+    /// installed under `name`.
+    pub fn get_ref_named<T: ?Sized + 'static>(
+        &self,
+        name: &'static str,
+    ) -> Result<&T, InjectError> {
+        let _resolving = Resolving::enter::<&T>(Some(name), self)?;
+        let (provider, container) = self.ref_provider::<T>(Some(name))?;
+        provider.provide(container)
     }
 
-    /// Clones the `Container`, returning a new container with the same providers. This is exactly
-    /// equal to `Container::clone(&container)`.
+    /// Creates a child `Container` of this container.
+    ///
+    /// A type the child has no provider for is resolved using its parent, including providers
+    /// installed into the parent after the child was created. Providers installed into the child
+    /// shadow those of the parent, without affecting the parent itself.
+    ///
+    /// Providers found in a parent are invoked with that parent, so instances they create, like
+    /// [`singleton!`](macro.singleton.html)s, are shared by all children and never depend on
+    /// providers installed into a child.
     ///
     /// # Example
     /// ```
     /// use inject::{Container, container};
     ///
-    /// let container = container![|container: &Container| Ok(2usize)];
-    /// let child_container = container.create_child();
+    /// let mut container = container![|container: &Container| Ok(2usize)];
+    /// let mut child_container = container.create_child();
+    ///
+    /// assert_eq!(child_container.get::<usize>(), container.get());
     ///
-    /// assert_eq!(child_container.get::<usize>(), container.get())
+    /// container.install(|container: &Container| Ok(3isize));
+    /// child_container.install(|container: &Container| Ok(4usize));
+    ///
+    /// assert_eq!(child_container.get::<isize>(), Ok(3));
+    /// assert_eq!(child_container.get::<usize>(), Ok(4));
+    /// assert_eq!(container.get::<usize>(), Ok(2));
     /// ```
     pub fn create_child(&self) -> Self {
-        self.clone()
+        Self {
+            bindings: Arc::default(),
+            parent: Some(Arc::new(self.share())),
+            scope: self.scope.clone(),
+            strict: self.strict,
+            overriding: false,
+        }
+    }
+
+    /// Enters a new scope, returning a child `Container` of this container (see
+    /// [`container.create_child()`](struct.Container.html#method.create_child)) for the duration
+    /// of the scope.
+    ///
+    /// Providers created by [`scoped!`](macro.scoped.html) provide one instance per scope, which
+    /// is dropped when the returned container and its children are. Values resolved using a
+    /// parent of the returned container are still resolved within the scope.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use inject::{container, get, scoped};
+    ///
+    /// #[derive(Default)]
+    /// struct Request {
+    ///     id: usize,
+    /// }
+    ///
+    /// let container = container![scoped!(Request)];
+    ///
+    /// let first_request = container.enter_scope();
+    /// let second_request = container.enter_scope();
+    ///
+    /// let a = get!(&first_request, Arc<Request>).unwrap();
+    /// let b = get!(&first_request, Arc<Request>).unwrap();
+    /// let c = get!(&second_request, Arc<Request>).unwrap();
+    ///
+    /// assert!(Arc::ptr_eq(&a, &b));
+    /// assert!(!Arc::ptr_eq(&a, &c));
+    /// ```
+    pub fn enter_scope(&self) -> Self {
+        Self {
+            scope: Some(Arc::default()),
+            ..self.create_child()
+        }
     }
 
-    fn box_provider<T: 'static, P: 'static + Provider<ProvidedType = T>>(
+    /// The innermost scope this container was created within, if any.
+    pub fn scope(&self) -> Option<&Scope> {
+        self.scope.as_deref()
+    }
+
+    /// A `Container` sharing the bindings of this container, rather than copying them.
+    fn share(&self) -> Self {
+        Self {
+            bindings: Arc::clone(&self.bindings),
+            parent: self.parent.clone(),
+            scope: self.scope.clone(),
+            strict: self.strict,
+            overriding: self.overriding,
+        }
+    }
+
+    /// The container a provider installed into this container is invoked with, when resolving a
+    /// value of `lifetime` through `container`, one of its descendants. That is the nearest
+    /// container in between overriding this one, if any, so the provider sees the overrides,
+    /// unless the value is cached and thus shared with this container. Either is used within the
+    /// scope of `container`.
+    fn on_behalf_of<'a>(
+        &'a self,
+        container: &'a Container,
+        lifetime: Lifetime,
+    ) -> Cow<'a, Container> {
+        let overriding = match lifetime {
+            Lifetime::Transient => container
+                .lineage()
+                .take_while(|ancestor| !Arc::ptr_eq(&ancestor.bindings, &self.bindings))
+                .find(|ancestor| ancestor.overriding),
+            Lifetime::Singleton | Lifetime::Scoped => None,
+        };
+        let resolver = overriding.unwrap_or(self);
+        let same_scope = match (&resolver.scope, &container.scope) {
+            (Some(own), Some(other)) => Arc::ptr_eq(own, other),
+            (own, other) => own.is_none() && other.is_none(),
+        };
+        if same_scope {
+            Cow::Borrowed(resolver)
+        } else {
+            Cow::Owned(Self {
+                scope: container.scope.clone(),
+                ..resolver.share()
+            })
+        }
+    }
+
+    /// This container followed by its ancestors, nearest first.
+    fn lineage(&self) -> impl Iterator<Item = &Container> {
+        std::iter::successors(Some(self), |container| container.parent.as_deref())
+    }
+
+    /// The binding for `key` in this container or its nearest ancestor, together with the
+    /// container it was installed into.
+    fn binding(&self, key: Key) -> Option<(Arc<dyn Any + Send + Sync>, &Container)> {
+        self.lineage().find_map(|container| {
+            container
+                .bindings
+                .get(key)
+                .map(|provider| (provider, container))
+        })
+    }
+
+    /// The container resolving `key` through this container: the nearest one with a binding for
+    /// it, or for its asynchronous counterpart, or this container if it is created by a fallback.
+    fn owner_of(&self, key: Key) -> &Container {
+        let async_key = match key {
+            Key::Single(id, name) => Some(Key::Async(id, name)),
+            _ => None,
+        };
+        self.lineage()
+            .find(|container| {
+                container.bindings.contains(key)
+                    || async_key.is_some_and(|key| container.bindings.contains(key))
+            })
+            .unwrap_or(self)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn provider<T: 'static>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Result<
+        (
+            Arc<Box<dyn Provider<ProvidedType = T> + Send + Sync>>,
+            &Container,
+        ),
+        InjectError,
+    > {
+        let (provider, container) = self
+            .binding(Key::of::<T>(name))
+            .ok_or_else(InjectError::missing_provider)?;
+        let provider = provider
+            .downcast::<Box<dyn Provider<ProvidedType = T> + Send + Sync>>()
+            .map_err(|_| InjectError::failed_cast())?;
+        Ok((provider, container))
+    }
+
+    /// A value provided by `provider`, installed into `container`, on behalf of this container,
+    /// passed through the decorators of its type.
+    fn provide<T: 'static>(
+        &self,
+        provider: &(dyn Provider<ProvidedType = T> + Send + Sync),
+        container: &Container,
+    ) -> Result<T, InjectError> {
+        self.decorated(provider.provide(&container.on_behalf_of(self, provider.lifetime()))?)
+    }
+
+    fn ref_provider<T: ?Sized + 'static>(
+        &self,
+        name: Option<&'static str>,
+    ) -> Result<(&dyn RefProvider<ProvidedRef = T>, &Container), InjectError> {
+        let key = Key::of::<&T>(name);
+        let (provider, container) = self
+            .lineage()
+            .find_map(|container| {
+                container
+                    .bindings
+                    .lend(key)
+                    .map(|provider| (provider, container))
+            })
+            .ok_or_else(InjectError::missing_provider)?;
+        let provider = provider
+            .downcast::<Box<dyn RefProvider<ProvidedRef = T> + Send + Sync>>()
+            .map_err(|_| InjectError::failed_cast())?;
+        // SAFETY: a lent provider is only dropped together with the bindings of `container`,
+        // which outlive the borrow of `self`, as `self` is or holds `container`.
+        let provider = unsafe { &*Arc::as_ptr(&provider) };
+        Ok((provider.as_ref(), container))
+    }
+
+    fn value_entry<T: Inject, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        name: Option<&'static str>,
+        provider: P,
+    ) -> Entry {
+        let eager = provider.is_eager();
+        let info = ProviderInfo::of::<T>(name, ProviderKind::Value).provided_by(&provider);
+        let mut entry = Entry::new(
+            Key::Single(provider.id(), name),
+            info,
+            Arc::new(Self::box_provider(provider)),
+            Some(validation::value::<T>),
+        );
+        entry.eager = eager;
+        entry
+    }
+
+    fn ref_entry<T: ?Sized + 'static, P: 'static + RefProvider<ProvidedRef = T> + Send + Sync>(
+        name: Option<&'static str>,
         provider: P,
-    ) -> Box<dyn Provider<ProvidedType = T>> {
+    ) -> Entry {
+        Entry::new(
+            Key::Single(provider.id(), name),
+            ProviderInfo::of::<&T>(name, ProviderKind::Reference)
+                .with_lifetime(Lifetime::Singleton),
+            Arc::new(Self::box_ref_provider(provider)),
+            Some(validation::reference::<T>),
+        )
+    }
+
+    fn box_provider<T: 'static, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        provider: P,
+    ) -> Box<dyn Provider<ProvidedType = T> + Send + Sync> {
         Box::new(provider)
     }
 
-    fn box_ref_provider<T: 'static, P: 'static + RefProvider<ProvidedRef = T>>(
+    fn box_ref_provider<
+        T: ?Sized + 'static,
+        P: 'static + RefProvider<ProvidedRef = T> + Send + Sync,
+    >(
         provider: P,
-    ) -> Box<dyn RefProvider<ProvidedRef = T>> {
+    ) -> Box<dyn RefProvider<ProvidedRef = T> + Send + Sync> {
         Box::new(provider)
     }
 }
+
+impl Clone for Container {
+    /// Clones the `Container`, copying its providers. Unlike a child, providers installed into
+    /// either container afterwards are not seen by the other.
+    fn clone(&self) -> Self {
+        Self {
+            bindings: Arc::new(Bindings::clone(&self.bindings)),
+            parent: self.parent.clone(),
+            scope: self.scope.clone(),
+            strict: self.strict,
+            overriding: self.overriding,
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Starting and stopping injected services
+//!
+//! A constructor annotated with `#[inject(post_construct = method)]` calls `method` on the
+//! constructed instance before handing it out. The method takes `&mut self` or `&self`, and
+//! returns either nothing or a `Result`, whose error fails the injection as
+//! `InjectError::ProviderFailed`.
+//!
+//! Types implementing [`Disposable`](trait.Disposable.html) are disposed by
+//! [`container.shutdown()`](../struct.Container.html#method.shutdown) when they are cached by a
+//! [`singleton!`](../macro.singleton.html) installed into the container. As a singleton is
+//! created after the singletons it depends on, disposing in reverse creation order stops
+//! dependents before their dependencies. The singletons then forget their instances, so a
+//! disposed instance is never handed out again.
+//!
+//! # Example
+//!
+//! ```
+//! use std::sync::atomic::{AtomicBool, Ordering};
+//! use std::sync::Arc;
+//!
+//! use ::inject::lifecycle::Disposable;
+//! use ::inject::{container, get, inject, singleton};
+//!
+//! #[derive(Default)]
+//! struct Pool {
+//!     open: AtomicBool,
+//! }
+//!
+//! impl Pool {
+//!     #[inject(post_construct = start)]
+//!     fn new() -> Self {
+//!         Self::default()
+//!     }
+//!
+//!     fn start(&mut self) {
+//!         self.open.store(true, Ordering::SeqCst);
+//!     }
+//! }
+//!
+//! impl Disposable for Pool {
+//!     fn dispose(&self) {
+//!         self.open.store(false, Ordering::SeqCst);
+//!     }
+//! }
+//!
+//! let container = container![singleton!(Pool)];
+//! let pool = get!(&container, Arc<Pool>).unwrap();
+//!
+//! assert!(pool.open.load(Ordering::SeqCst));
+//!
+//! container.shutdown();
+//!
+//! assert!(!pool.open.load(Ordering::SeqCst));
+//! ```
+use std::error::Error;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use crate::Container;
+use crate::InjectError;
+
+/// A type holding resources which are released by
+/// [`container.shutdown()`](../struct.Container.html#method.shutdown).
+pub trait Disposable {
+    /// Release the resources of this instance. Called at most once per
+    /// [`singleton!`](../macro.singleton.html) instance.
+    fn dispose(&self);
+}
+
+/// The result of a `post_construct` method, see the [module documentation](index.html).
+pub trait PostConstruct {
+    /// Whether the method succeeded.
+    fn into_result(self) -> Result<(), InjectError>;
+}
+
+impl PostConstruct for () {
+    fn into_result(self) -> Result<(), InjectError> {
+        Ok(())
+    }
+}
+
+impl<E: Into<Box<dyn Error + Send + Sync>>> PostConstruct for Result<(), E> {
+    fn into_result(self) -> Result<(), InjectError> {
+        self.map_err(InjectError::provider_failed)
+    }
+}
+
+impl Container {
+    /// Dispose the [`Disposable`](lifecycle/trait.Disposable.html) instances created by the
+    /// [`singleton!`](macro.singleton.html)s installed into this container, most recently
+    /// created first. Each instance is disposed once, even if `shutdown` is called again.
+    ///
+    /// The singletons forget their instances, so resolving them afterwards creates new ones
+    /// rather than handing out disposed instances. Instances created by singletons installed into
+    /// the ancestors of this container are left to their shutdown.
+    pub fn shutdown(&self) {
+        let releases = std::mem::take(&mut *self.bindings.releases.lock().unwrap());
+        for release in releases.into_iter().rev() {
+            release();
+        }
+    }
+
+    /// Have the next [`container.shutdown()`](struct.Container.html#method.shutdown) run
+    /// `release`.
+    fn on_shutdown(&self, release: impl FnOnce() + Send + 'static) {
+        self.bindings
+            .releases
+            .lock()
+            .unwrap()
+            .push(Box::new(release));
+    }
+}
+
+/// Registers the instances created by [`singleton!`](../macro.singleton.html) to be forgotten by
+/// `cache` on shutdown, and disposed if they are [`Disposable`](trait.Disposable.html).
+#[doc(hidden)]
+pub struct Register<T>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait RegisterDisposable<T> {
+    fn register(&self, container: &Container, instance: &Arc<T>, cache: &Cache<T>);
+}
+
+impl<T: Disposable + Send + Sync + 'static> RegisterDisposable<T> for &Register<T> {
+    fn register(&self, container: &Container, instance: &Arc<T>, cache: &Cache<T>) {
+        let instance = Arc::clone(instance);
+        let cache = Arc::clone(cache);
+        container.on_shutdown(move || {
+            cache.lock().unwrap().take();
+            instance.dispose();
+        });
+    }
+}
+
+#[doc(hidden)]
+pub trait RegisterOther<T: Send + Sync + 'static> {
+    fn register(&self, container: &Container, _instance: &Arc<T>, cache: &Cache<T>) {
+        let cache = Arc::clone(cache);
+        container.on_shutdown(move || {
+            cache.lock().unwrap().take();
+        });
+    }
+}
+
+impl<T: Send + Sync + 'static> RegisterOther<T> for Register<T> {}
+
+/// The instance cached by a [`singleton!`](../macro.singleton.html).
+#[doc(hidden)]
+pub type Cache<T> = Arc<Mutex<Option<Arc<T>>>>;
@@ -1,12 +1,162 @@
+//! Grouping of provider registrations
+//!
+//! A [`Module`](trait.Module.html) installs a related set of providers into a container, so that
+//! each crate can ship its own bindings. Modules are installed using
+//! [`container.install_module(..)`](../struct.Container.html#method.install_module), or by
+//! prefixing an argument of [`container!`](../macro.container.html) with `module`.
+//!
+//! [`Modules`](struct.Modules.html) composes several modules into one, and
+//! [`modules.override_with(..)`](struct.Modules.html#method.override_with) replaces some of
+//! their bindings, for instance with fakes in tests.
+//!
+//! # Example
+//!
+//! ```
+//! use inject::module::{Module, Modules};
+//! use inject::{container, get, Container};
+//!
+//! struct DbModule;
+//!
+//! impl Module for DbModule {
+//!     fn configure(&self, binder: &mut Container) {
+//!         binder.install_named("db_url", |_: &Container| Ok(String::from("postgres://")));
+//!         binder.install(|_: &Container| Ok(5432u16));
+//!     }
+//! }
+//!
+//! struct HttpModule;
+//!
+//! impl Module for HttpModule {
+//!     fn configure(&self, binder: &mut Container) {
+//!         binder.install(|_: &Container| Ok(8080usize));
+//!     }
+//! }
+//!
+//! let container = container![module DbModule, module HttpModule];
+//!
+//! assert_eq!(Ok("postgres://".into()), get!(&container, String, named: "db_url"));
+//! assert_eq!(Ok(8080), get!(&container, usize));
+//!
+//! let test_module = Modules::new()
+//!     .with(DbModule)
+//!     .with(HttpModule)
+//!     .override_with(|binder: &mut Container| binder.install(|_: &Container| Ok(0u16)));
+//!
+//! let container = container![module test_module];
+//!
+//! assert_eq!(Ok(0), get!(&container, u16));
+//! assert_eq!(Ok(8080), get!(&container, usize));
+//! ```
+use std::fmt;
+
+use crate::Container;
+
+/// A group of providers, installed together into a [`Container`](../struct.Container.html).
+///
+/// Closures taking a `&mut Container` are modules as well.
+pub trait Module {
+    /// Install the providers of this module into `binder`.
+    fn configure(&self, binder: &mut Container);
+}
+
+impl<F: Fn(&mut Container)> Module for F {
+    fn configure(&self, binder: &mut Container) {
+        self(binder)
+    }
+}
+
+/// Several [`Module`](trait.Module.html)s, configured in the order they were added.
+///
+/// As the most recently installed provider of a type is the one resolved, a module added later
+/// overrides the bindings of the modules before it. Providers contributed to a set or map are
+/// added to, rather than replaced.
+#[derive(Default)]
+pub struct Modules {
+    modules: Vec<Box<dyn Module>>,
+}
+
+impl Modules {
+    /// Create an empty `Modules`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `module`, configured after the modules already added.
+    pub fn with(mut self, module: impl Module + 'static) -> Self {
+        self.modules.push(Box::new(module));
+        self
+    }
+
+    /// These modules, with their bindings replaced by those of `overrides` where both bind the
+    /// same type, even when installed into a [strict](../struct.Container.html#method.strict)
+    /// container. Bindings of `overrides` for other types are installed as well.
+    pub fn override_with(self, overrides: impl Module + 'static) -> Self {
+        Self::new().with(self).with(Overrides(overrides))
+    }
+}
+
+impl Module for Modules {
+    fn configure(&self, binder: &mut Container) {
+        for module in &self.modules {
+            module.configure(binder);
+        }
+    }
+}
+
+/// A module whose bindings replace those installed before it.
+struct Overrides<M>(M);
+
+impl<M: Module> Module for Overrides<M> {
+    fn configure(&self, binder: &mut Container) {
+        binder.replacing(|binder| self.0.configure(binder))
+    }
+}
+
+impl fmt::Debug for Modules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Modules")
+            .field("len", &self.modules.len())
+            .finish()
+    }
+}
+
+impl Container {
+    /// Install the providers of `module` into this `Container`, see
+    /// [`Module`](module/trait.Module.html).
+    pub fn install_module(&mut self, module: impl Module) {
+        module.configure(self);
+    }
+}
+
 #[macro_export]
 macro_rules! singleton {
-    ($injectable:ty) => {{
+    ($injectable:ty, eager) => {
+        $crate::singleton!(@provider $injectable, true)
+    };
+    ($injectable:ty) => {
+        $crate::singleton!(@provider $injectable, false)
+    };
+    (@provider $injectable:ty, $eager:expr) => {{
         struct SingletonProvider {
-            instance: std::sync::Mutex<Option<std::sync::Arc<$injectable>>>,
+            instance: $crate::lifecycle::Cache<$injectable>,
         }
 
         impl $crate::Provider for SingletonProvider {
             type ProvidedType = std::sync::Arc<$injectable>;
+
+            fn is_eager(&self) -> bool {
+                $eager
+            }
+
+            fn lifetime(&self) -> $crate::graph::Lifetime {
+                $crate::graph::Lifetime::Singleton
+            }
+
+            fn dependencies(&self) -> Vec<$crate::graph::Dependency> {
+                use $crate::Inject;
+                <$injectable>::inject_dependencies()
+            }
+
             fn provide(
                 &self,
                 c: &$crate::Container,
@@ -20,7 +170,16 @@ macro_rules! singleton {
                         let maybe_instance = $crate::get!(&c, $injectable);
                         match maybe_instance {
                             Ok(maybe_instance) => {
-                                *instance = Some(std::sync::Arc::new(maybe_instance));
+                                let created = std::sync::Arc::new(maybe_instance);
+                                {
+                                    #[allow(unused_imports)]
+                                    use $crate::lifecycle::{RegisterDisposable, RegisterOther};
+                                    (&&$crate::lifecycle::Register::<$injectable>(
+                                        std::marker::PhantomData,
+                                    ))
+                                        .register(c, &created, &self.instance);
+                                }
+                                *instance = Some(created);
                             }
                             Err(err) => return Err(err),
                         }
@@ -29,7 +188,37 @@ macro_rules! singleton {
             }
         }
         SingletonProvider {
-            instance: std::sync::Mutex::default(),
+            instance: std::sync::Arc::default(),
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! scoped {
+    ($injectable:ty) => {{
+        struct ScopedProvider;
+
+        impl $crate::Provider for ScopedProvider {
+            type ProvidedType = std::sync::Arc<$injectable>;
+
+            fn lifetime(&self) -> $crate::graph::Lifetime {
+                $crate::graph::Lifetime::Scoped
+            }
+
+            fn dependencies(&self) -> Vec<$crate::graph::Dependency> {
+                use $crate::Inject;
+                <$injectable>::inject_dependencies()
+            }
+
+            fn provide(
+                &self,
+                c: &$crate::Container,
+            ) -> Result<Self::ProvidedType, $crate::InjectError> {
+                c.scope()
+                    .ok_or_else($crate::InjectError::missing_scope)?
+                    .get_or_insert_with::<Self, _>(|| $crate::get!(&c, $injectable))
+            }
         }
+        ScopedProvider
     }};
 }
@@ -0,0 +1,209 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::bindings::Entry;
+use crate::graph::{ProviderInfo, ProviderKind};
+use crate::inject::Inject;
+use crate::key::Key;
+use crate::provider::Provider;
+use crate::resolution::Resolving;
+use crate::validation;
+use crate::Container;
+use crate::InjectError;
+
+/// A provider contributed to a map binding, together with its key.
+struct MapEntry<K, T> {
+    key: K,
+    provider: Arc<dyn Provider<ProvidedType = T> + Send + Sync>,
+}
+
+impl Container {
+    /// Contribute a [`Provider`](provider/trait.Provider.html) to the set of providers for its
+    /// type. Unlike [`container.install(..)`](struct.Container.html#method.install), contributions
+    /// accumulate instead of replacing each other, and are resolved together using
+    /// [`container.get_all()`](struct.Container.html#method.get_all).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{Container, get};
+    ///
+    /// let mut container = Container::new();
+    /// container.install_into_set(|_: &Container| Ok("users"));
+    /// container.install_into_set(|_: &Container| Ok("orders"));
+    ///
+    /// assert_eq!(Ok(vec!["users", "orders"]), get!(&container, Vec<&str>));
+    /// ```
+    pub fn install_into_set<T: Inject, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        &mut self,
+        provider: P,
+    ) {
+        let info = ProviderInfo::of::<Vec<T>>(None, ProviderKind::Set).provided_by(&provider);
+        self.bindings.push(Entry::new(
+            Key::Set(provider.id()),
+            info,
+            Arc::new(Self::box_provider(provider)),
+            Some(validation::set::<T>),
+        ));
+    }
+
+    /// Resolve every [`Provider`](provider/trait.Provider.html) contributed using
+    /// [`container.install_into_set(..)`](struct.Container.html#method.install_into_set), in the
+    /// order they were installed. Resolves to an empty `Vec` if nothing was contributed.
+    ///
+    /// Contributions installed into the ancestors of this container are resolved first, using
+    /// the container they were installed into, within the scope of this container.
+    pub fn get_all<T: Inject>(&self) -> Result<Vec<T>, InjectError> {
+        let _resolving = Resolving::enter_unless_innermost::<Vec<T>>(self)?;
+        let key = Key::set::<T>();
+        self.contributions(key)
+            .into_iter()
+            .map(|(provider, container)| {
+                let _resolving = Resolving::enter_contribution::<T>(key, container)?;
+                let provider = provider
+                    .downcast_ref::<Box<dyn Provider<ProvidedType = T> + Send + Sync>>()
+                    .ok_or_else(InjectError::failed_cast)?;
+                provider.provide(&container.on_behalf_of(self, provider.lifetime()))
+            })
+            .collect()
+    }
+
+    /// Contribute a [`Provider`](provider/trait.Provider.html) to the map of providers for its
+    /// type, under `key`. Contributions accumulate, and are resolved together using
+    /// [`container.get_map()`](struct.Container.html#method.get_map) or
+    /// [`container.get_map_lazy()`](struct.Container.html#method.get_map_lazy). If several
+    /// contributions share a key, the last one installed is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use inject::{Container, get, InjectError};
+    ///
+    /// let mut container = Container::new();
+    /// container.install_into_map("add", |_: &Container| Ok(1isize));
+    /// container.install_into_map("sub", |_: &Container| Ok(-1isize));
+    ///
+    /// let steps = get!(&container, HashMap<&str, isize>).unwrap();
+    /// assert_eq!(steps["sub"], -1);
+    ///
+    /// let lazy_steps = get!(
+    ///     &container,
+    ///     HashMap<&str, Box<dyn Fn() -> Result<isize, InjectError>>>
+    /// ).unwrap();
+    /// assert_eq!(lazy_steps["add"](), Ok(1));
+    /// ```
+    pub fn install_into_map<K, T, P>(&mut self, key: K, provider: P)
+    where
+        K: Eq + Hash + Clone + Send + Sync + 'static,
+        T: Inject,
+        P: 'static + Provider<ProvidedType = T> + Send + Sync,
+    {
+        let info =
+            ProviderInfo::of::<HashMap<K, T>>(None, ProviderKind::Map).provided_by(&provider);
+        let entry = MapEntry {
+            key,
+            provider: Arc::new(provider),
+        };
+        self.bindings.push(Entry::new(
+            Key::map::<K, T>(),
+            info,
+            Arc::new(entry),
+            Some(validation::map::<K, T>),
+        ));
+    }
+
+    /// Resolve every [`Provider`](provider/trait.Provider.html) contributed using
+    /// [`container.install_into_map(..)`](struct.Container.html#method.install_into_map),
+    /// keyed by the key it was contributed under. Resolves to an empty `HashMap` if nothing was
+    /// contributed.
+    ///
+    /// Contributions installed into this container take precedence over those installed into its
+    /// ancestors, and are resolved using the container they were installed into, within the
+    /// scope of this container.
+    pub fn get_map<K, T>(&self) -> Result<HashMap<K, T>, InjectError>
+    where
+        K: Eq + Hash + Clone + Send + Sync + 'static,
+        T: Inject,
+    {
+        let _resolving = Resolving::enter_unless_innermost::<HashMap<K, T>>(self)?;
+        self.map_entries::<K, T>()?
+            .into_iter()
+            .map(|(entry, container)| {
+                let _resolving = Resolving::enter_contribution::<T>(Key::map::<K, T>(), container)?;
+                let container = container.on_behalf_of(self, entry.provider.as_ref().lifetime());
+                Ok((
+                    entry.key.clone(),
+                    entry.provider.as_ref().provide(&container)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Like [`container.get_map()`](struct.Container.html#method.get_map), but the contributed
+    /// providers are only invoked when the corresponding function is called.
+    #[allow(clippy::type_complexity)]
+    pub fn get_map_lazy<K, T>(
+        &self,
+    ) -> Result<HashMap<K, Box<dyn Fn() -> Result<T, InjectError>>>, InjectError>
+    where
+        K: Eq + Hash + Clone + Send + Sync + 'static,
+        T: Inject,
+    {
+        let _resolving = Resolving::enter_unless_innermost::<
+            HashMap<K, Box<dyn Fn() -> Result<T, InjectError>>>,
+        >(self)?;
+        Ok(self
+            .map_entries::<K, T>()?
+            .into_iter()
+            .map(|(entry, container)| {
+                let owner = container.share();
+                let container = container
+                    .on_behalf_of(self, entry.provider.as_ref().lifetime())
+                    .into_owned();
+                let provider = Arc::clone(&entry.provider);
+                let factory: Box<dyn Fn() -> Result<T, InjectError>> = Box::new(move || {
+                    let _resolving =
+                        Resolving::enter_contribution::<T>(Key::map::<K, T>(), &owner)?;
+                    provider.as_ref().provide(&container)
+                });
+                (entry.key.clone(), factory)
+            })
+            .collect())
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn map_entries<K: Send + Sync + 'static, T: 'static>(
+        &self,
+    ) -> Result<Vec<(Arc<MapEntry<K, T>>, &Container)>, InjectError> {
+        self.contributions(Key::map::<K, T>())
+            .into_iter()
+            .map(|(entry, container)| {
+                let entry = entry
+                    .downcast::<MapEntry<K, T>>()
+                    .map_err(|_| InjectError::failed_cast())?;
+                Ok((entry, container))
+            })
+            .collect()
+    }
+
+    /// Every contribution for `key` installed into this container and its ancestors, outermost
+    /// ancestor first, together with the container it was installed into.
+    pub(crate) fn contributions(&self, key: Key) -> Vec<(Arc<dyn Any + Send + Sync>, &Container)> {
+        let mut lineage: Vec<_> = self.lineage().collect();
+        lineage.reverse();
+        lineage
+            .into_iter()
+            .flat_map(|container| {
+                container
+                    .bindings
+                    .get_all(key)
+                    .into_iter()
+                    .map(move |provider| (provider, container))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,118 @@
+use crate::inject::Inject;
+use crate::resolution::Resolving;
+use crate::Container;
+use crate::InjectError;
+
+impl Container {
+    /// Resolve a value-type like [`container.get()`](struct.Container.html#method.get), or `None`
+    /// if no [`Provider`](provider/trait.Provider.html) is installed for it. Errors raised while
+    /// providing it, including missing providers of its own dependencies, are still returned.
+    ///
+    /// [`get!`](macro.get.html) resolves `Option<&T>` using these methods, and `Option<T>` using
+    /// [`container.try_get_or_else(..)`](struct.Container.html#method.try_get_or_else) unless
+    /// `T` can only be resolved using providers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{container, get, Container, InjectError};
+    ///
+    /// let container = container![
+    ///     |_: &Container| Ok(2usize),
+    ///     |_: &Container| Err::<isize, _>(InjectError::missing_provider()),
+    ///     ref Box::new(5u16),
+    /// ];
+    ///
+    /// assert_eq!(Ok(Some(2)), container.try_get::<usize>());
+    /// assert_eq!(Ok(None), container.try_get::<String>());
+    /// assert!(container.try_get::<isize>().is_err());
+    ///
+    /// assert_eq!(Ok(Some(&5)), get!(&container, Option<&u16>));
+    /// assert_eq!(Ok(None), get!(&container, Option<&u32>));
+    /// ```
+    pub fn try_get<T: Inject>(&self) -> Result<Option<T>, InjectError> {
+        let _resolving = Resolving::enter::<T>(None, self)?;
+        optional(self.provider::<T>(None))?
+            .map(|(provider, container)| self.provide(&**provider, container))
+            .transpose()
+    }
+
+    /// Resolve a value-type like [`container.try_get()`](struct.Container.html#method.try_get),
+    /// but create it using `fallback` if no [`Provider`](provider/trait.Provider.html) is installed
+    /// for it, like [`container.get_or_else(..)`](struct.Container.html#method.get_or_else).
+    /// Resolves to `None` if `fallback` fails for lack of a provider.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use ::inject::{container, get, inject};
+    ///
+    /// struct Config(u16);
+    ///
+    /// struct Pool(Arc<Config>);
+    ///
+    /// impl Pool {
+    ///     #[inject]
+    ///     fn new(config: Arc<Config>) -> Self {
+    ///         Self(config)
+    ///     }
+    /// }
+    ///
+    /// assert!(get!(&container![], Option<Pool>).unwrap().is_none());
+    ///
+    /// let container = container![Arc::new(Config(5432))];
+    ///
+    /// assert_eq!(5432, get!(&container, Option<Pool>).unwrap().unwrap().0 .0);
+    /// ```
+    pub fn try_get_or_else<T: Inject>(
+        &self,
+        fallback: impl FnOnce(&Container) -> Result<T, InjectError>,
+    ) -> Result<Option<T>, InjectError> {
+        let _resolving = Resolving::enter::<T>(None, self)?;
+        match optional(self.provider::<T>(None))? {
+            Some((provider, container)) => self.provide(&**provider, container).map(Some),
+            None => optional(fallback(self)),
+        }
+    }
+
+    /// Resolve a value-type from the [`Provider`](provider/trait.Provider.html) installed under
+    /// `name`, or `None` if there is none.
+    pub fn try_get_named<T: Inject>(&self, name: &'static str) -> Result<Option<T>, InjectError> {
+        let _resolving = Resolving::enter::<T>(Some(name), self)?;
+        optional(self.provider::<T>(Some(name)))?
+            .map(|(provider, container)| self.provide(&**provider, container))
+            .transpose()
+    }
+
+    /// Resolve a reference-type from the installed
+    /// [`RefProvider`](provider/trait.RefProvider.html)s, or `None` if there is none.
+    pub fn try_get_ref<T: ?Sized + 'static>(&self) -> Result<Option<&T>, InjectError> {
+        let _resolving = Resolving::enter::<&T>(None, self)?;
+        optional(self.ref_provider::<T>(None))?
+            .map(|(provider, container)| provider.provide(container))
+            .transpose()
+    }
+
+    /// Resolve a reference-type from the [`RefProvider`](provider/trait.RefProvider.html)
+    /// installed under `name`, or `None` if there is none.
+    pub fn try_get_ref_named<T: ?Sized + 'static>(
+        &self,
+        name: &'static str,
+    ) -> Result<Option<&T>, InjectError> {
+        let _resolving = Resolving::enter::<&T>(Some(name), self)?;
+        optional(self.ref_provider::<T>(Some(name)))?
+            .map(|(provider, container)| provider.provide(container))
+            .transpose()
+    }
+}
+
+/// `None` if the provider looked up is missing.
+pub(crate) fn optional<P>(provider: Result<P, InjectError>) -> Result<Option<P>, InjectError> {
+    match provider {
+        Ok(provider) => Ok(Some(provider)),
+        Err(InjectError::MissingProvider { .. }) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
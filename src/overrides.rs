@@ -0,0 +1,55 @@
+use crate::inject::Inject;
+use crate::provider::Provider;
+use crate::Container;
+
+impl Container {
+    /// Create a child of this container (see
+    /// [`container.create_child()`](struct.Container.html#method.create_child)) with some
+    /// providers overridden using `configure`, leaving this container and its other children
+    /// untouched. The overrides are dropped together with the returned container.
+    ///
+    /// Types resolved through the returned container see the overrides, including the
+    /// dependencies of types it creates with their `inject` function, and of types provided by
+    /// this container: its providers are invoked with the returned container instead. Values
+    /// cached by [`singleton!`](macro.singleton.html) and [`scoped!`](macro.scoped.html) are
+    /// shared with this container, so their providers are still invoked with it, and the cached
+    /// instance wins over the overrides. Providers can be replaced by `configure` even if the
+    /// container is [strict](struct.Container.html#method.strict).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{container, get, Container};
+    ///
+    /// let container = container![|_: &Container| Ok(String::from("postgres://"))];
+    ///
+    /// let overridden = container.with_overrides(|container| {
+    ///     container.install(|_: &Container| Ok(String::from("sqlite::memory:")));
+    ///     container.install(|_: &Container| Ok(1usize));
+    /// });
+    ///
+    /// assert_eq!(Ok("sqlite::memory:".into()), get!(&overridden, String));
+    /// assert_eq!(Ok(1), get!(&overridden, usize));
+    ///
+    /// assert_eq!(Ok("postgres://".into()), get!(&container, String));
+    /// assert!(get!(&container, usize, create: false).is_err());
+    /// ```
+    pub fn with_overrides(&self, configure: impl FnOnce(&mut Container)) -> Container {
+        let mut overridden = Self {
+            overriding: true,
+            ..self.create_child()
+        };
+        overridden.replacing(configure);
+        overridden
+    }
+
+    /// Create a child of this container with the [`Provider`](provider/trait.Provider.html) of
+    /// its type overridden by `provider`, see
+    /// [`container.with_overrides(..)`](struct.Container.html#method.with_overrides).
+    pub fn override_provider<T: Inject, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        &self,
+        provider: P,
+    ) -> Container {
+        self.with_overrides(|container| container.install(provider))
+    }
+}
@@ -10,7 +10,7 @@
 //!
 //! # Examples
 //!
-//! For convenience, `Box`, `Rc` and `Arc` types all implement `RefProvider`.
+//! For convenience, `Box` and `Arc` types implement `RefProvider`.
 //! ```
 //! use inject::{container, get, RefProvider};
 //!
@@ -47,11 +47,29 @@
 //!
 //! assert_eq!(Ok(5), get!(&container, usize))
 //! ```
+//!
+//! When a provider provides a value asynchronously, it implements
+//! [`AsyncProvider`](trait.AsyncProvider.html). Closures returning a boxed future implement it too,
+//! and are best wrapped in [`async_fn(..)`](fn.async_fn.html) to have their signature inferred.
+//!
+//! ```
+//! use inject::{container, get, Container};
+//! use inject::provider::async_fn;
+//!
+//! let container = container![
+//!     async async_fn(|container: &Container| Box::pin(async move { Ok(5usize) }))
+//! ];
+//!
+//! let result = pollster::block_on(get!(async &container, usize));
+//! assert_eq!(Ok(5), result)
+//! ```
 
 use std::any::TypeId;
-use std::rc::Rc;
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
 
+use crate::graph::{Dependency, Lifetime};
 use crate::inject::Inject;
 use crate::Container;
 use crate::InjectError;
@@ -67,13 +85,37 @@ pub trait Provider {
     fn id(&self) -> TypeId {
         TypeId::of::<Self::ProvidedType>()
     }
+
+    /// Whether the value is provided when the container is built, using
+    /// [`container.build()`](../struct.Container.html#method.build), rather than when first
+    /// resolved. `false` unless overridden.
+    fn is_eager(&self) -> bool {
+        false
+    }
+
+    /// The types resolved when providing the value, listed by
+    /// [`container.graph()`](../struct.Container.html#method.graph). Empty unless overridden.
+    fn dependencies(&self) -> Vec<Dependency> {
+        Vec::new()
+    }
+
+    /// How long a provided value is reused for, listed by
+    /// [`container.providers()`](../struct.Container.html#method.providers).
+    /// [`Lifetime::Transient`](../graph/enum.Lifetime.html#variant.Transient) unless overridden.
+    fn lifetime(&self) -> Lifetime {
+        Lifetime::Transient
+    }
 }
 
 impl<T: ?Sized + 'static> Provider for Arc<T> {
     type ProvidedType = Self;
 
+    fn lifetime(&self) -> Lifetime {
+        Lifetime::Singleton
+    }
+
     fn provide(&self, _container: &Container) -> Result<Self::ProvidedType, InjectError> {
-        Ok(Arc::clone(&self))
+        Ok(Arc::clone(self))
     }
 }
 
@@ -87,9 +129,48 @@ where
     }
 }
 
+/// The future of a value provided by an [`AsyncProvider`](trait.AsyncProvider.html).
+pub type ProvideFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, InjectError>> + Send + 'a>>;
+
+/// Asynchronous value provider.
+pub trait AsyncProvider {
+    type ProvidedType: 'static;
+
+    /// Provides the value using the `Container`, once the returned future completes
+    fn provide_async<'a>(
+        &'a self,
+        container: &'a Container,
+    ) -> ProvideFuture<'a, Self::ProvidedType>;
+
+    /// Returns the type id of the provided type.
+    fn id(&self) -> TypeId {
+        TypeId::of::<Self::ProvidedType>()
+    }
+}
+
+impl<F, T: Inject> AsyncProvider for F
+where
+    F: for<'a> Fn(&'a Container) -> ProvideFuture<'a, T>,
+{
+    type ProvidedType = T;
+    fn provide_async<'a>(&'a self, container: &'a Container) -> ProvideFuture<'a, T> {
+        self(container)
+    }
+}
+
+/// Returns `provider` as is, letting the compiler infer the signature of a closure returning a
+/// [`ProvideFuture`](type.ProvideFuture.html), so it implements
+/// [`AsyncProvider`](trait.AsyncProvider.html).
+pub fn async_fn<T, F>(provider: F) -> F
+where
+    F: for<'a> Fn(&'a Container) -> ProvideFuture<'a, T>,
+{
+    provider
+}
+
 /// Reference provider.
 pub trait RefProvider {
-    type ProvidedRef: 'static;
+    type ProvidedRef: ?Sized + 'static;
 
     /// Provides the reference using the `Container`
     fn provide<'a>(
@@ -103,26 +184,18 @@ pub trait RefProvider {
     }
 }
 
-impl<T: Inject> RefProvider for Arc<T> {
-    type ProvidedRef = T;
-
-    fn provide<'a>(&'a self, _: &'a Container) -> Result<&'a T, InjectError> {
-        Ok(&self)
-    }
-}
-
-impl<T: Inject> RefProvider for Rc<T> {
+impl<T: ?Sized + 'static> RefProvider for Arc<T> {
     type ProvidedRef = T;
 
     fn provide<'a>(&'a self, _: &'a Container) -> Result<&'a T, InjectError> {
-        Ok(&self)
+        Ok(self)
     }
 }
 
-impl<T: Inject> RefProvider for Box<T> {
+impl<T: ?Sized + 'static> RefProvider for Box<T> {
     type ProvidedRef = T;
 
     fn provide<'a>(&'a self, _: &'a Container) -> Result<&'a T, InjectError> {
-        Ok(&self)
+        Ok(self)
     }
 }
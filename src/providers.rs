@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use crate::graph::Lifetime;
 use crate::inject::Inject;
 use crate::provider::Provider;
 use crate::Container;
@@ -13,6 +14,10 @@ pub struct InstanceProvider<T: Inject> {
 impl<T: Inject> Provider for InstanceProvider<T> {
     type ProvidedType = Arc<T>;
 
+    fn lifetime(&self) -> Lifetime {
+        Lifetime::Singleton
+    }
+
     fn provide(&self, _: &Container) -> Result<Self::ProvidedType, InjectError> {
         Ok(self.instance.clone())
     }
@@ -25,7 +30,10 @@ impl<T: Inject> InstanceProvider<T> {
         }
     }
 
-    pub fn install_into(self, container: &mut Container) {
+    pub fn install_into(self, container: &mut Container)
+    where
+        T: Send + Sync,
+    {
         let cloned = Arc::clone(&self.instance);
         container.install(self);
         container.install_ref(cloned);
@@ -0,0 +1,133 @@
+//! The types being resolved on the current thread, outermost first, giving
+//! [`InjectError`](../error/enum.InjectError.html)s their resolution path, and detecting cycles.
+//!
+//! A binding is identified by its key and the bindings it is looked up in, so a provider
+//! resolving the same type using another container, like a parent or an unrelated container, is
+//! not mistaken for a cycle.
+use std::any::type_name;
+use std::cell::RefCell;
+use std::future::{self, Future};
+use std::iter;
+use std::sync::Arc;
+use std::task::Poll;
+
+use crate::key::Key;
+use crate::{Container, InjectError};
+
+thread_local! {
+    static PATH: RefCell<Vec<(Key, usize, &'static str)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Identifies the bindings of `container`, which are shared by the copies of it resolving within
+/// other scopes.
+fn owner(container: &Container) -> usize {
+    Arc::as_ptr(&container.bindings) as usize
+}
+
+/// Marks the binding of `T` as being resolved on the current thread, until dropped.
+pub(crate) struct Resolving(());
+
+impl Resolving {
+    /// Fails with `InjectError::Cycle` if the binding of `T` resolved by `container` is already
+    /// being resolved.
+    pub fn enter<T: ?Sized + 'static>(
+        name: Option<&'static str>,
+        container: &Container,
+    ) -> Result<Self, InjectError> {
+        let key = Key::of::<T>(name);
+        Self::enter_key(key, owner(container.owner_of(key)), type_name::<T>())
+    }
+
+    /// Like [`Resolving::enter`], unless the binding of `T` is the innermost one being resolved
+    /// already, as when `T` is created by the fallback of `container.get_or_else(..)`.
+    pub fn enter_unless_innermost<T: ?Sized + 'static>(
+        container: &Container,
+    ) -> Result<Option<Self>, InjectError> {
+        let binding = (
+            Key::of::<T>(None),
+            owner(container.owner_of(Key::of::<T>(None))),
+        );
+        let innermost =
+            PATH.with(|path| path.borrow().last().map(|(key, owner, _)| (*key, *owner)));
+        if innermost == Some(binding) {
+            Ok(None)
+        } else {
+            Self::enter::<T>(None, container).map(Some)
+        }
+    }
+
+    /// Marks a contribution of type `T` to the set or map bound to `key`, installed into
+    /// `container`, as being resolved, until dropped.
+    pub fn enter_contribution<T: ?Sized + 'static>(
+        key: Key,
+        container: &Container,
+    ) -> Result<Self, InjectError> {
+        Self::enter_key(key, owner(container), type_name::<T>())
+    }
+
+    fn enter_key(key: Key, owner: usize, type_name: &'static str) -> Result<Self, InjectError> {
+        let cycle = PATH.with(|path| {
+            let mut path = path.borrow_mut();
+            let start = path
+                .iter()
+                .position(|(resolving, resolved_by, _)| *resolving == key && *resolved_by == owner);
+            match start {
+                Some(start) => Some(
+                    path[start..]
+                        .iter()
+                        .map(|(_, _, type_name)| *type_name)
+                        .chain(iter::once(type_name))
+                        .collect(),
+                ),
+                None => {
+                    path.push((key, owner, type_name));
+                    None
+                }
+            }
+        });
+        match cycle {
+            Some(cycle) => Err(InjectError::cycle(cycle)),
+            None => Ok(Self(())),
+        }
+    }
+}
+
+impl Drop for Resolving {
+    fn drop(&mut self) {
+        PATH.with(|path| path.borrow_mut().pop());
+    }
+}
+
+/// Completes with `future`, the resolution of the binding of `T` by `container`, which is marked as
+/// being resolved whenever `future` is polled. Futures resolving dependencies are polled while
+/// polling their dependents, so the path is kept across `.await`s, even if the future moves
+/// between threads.
+pub(crate) fn resolving<T, O, F>(
+    name: Option<&'static str>,
+    container: &Container,
+    future: F,
+) -> impl Future<Output = Result<O, InjectError>>
+where
+    T: ?Sized + 'static,
+    F: Future<Output = Result<O, InjectError>>,
+{
+    let key = Key::of::<T>(name);
+    let owner = owner(container.owner_of(key));
+    let mut future = Box::pin(future);
+    future::poll_fn(
+        move |cx| match Resolving::enter_key(key, owner, type_name::<T>()) {
+            Ok(_resolving) => future.as_mut().poll(cx),
+            Err(err) => Poll::Ready(Err(err)),
+        },
+    )
+}
+
+/// The types currently being resolved on this thread, outermost first.
+pub(crate) fn path() -> Vec<&'static str> {
+    PATH.with(|path| {
+        path.borrow()
+            .iter()
+            .map(|(_, _, type_name)| *type_name)
+            .collect()
+    })
+}
@@ -0,0 +1,48 @@
+//! Scopes for [`scoped!`](../macro.scoped.html) providers
+//!
+//! A scope is entered using [`container.enter_scope()`](../struct.Container.html#method.enter_scope),
+//! which returns a child container. Every `scoped!` provider caches one instance per scope, which
+//! is dropped together with the last container of the scope.
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::InjectError;
+
+/// Instances cached by the scoped providers resolved within a scope.
+#[derive(Debug, Default)]
+pub struct Scope {
+    instances: Mutex<HashMap<TypeId, Box<dyn Any + Send + Sync>>>,
+}
+
+impl Scope {
+    /// Returns the instance cached for the provider `P`, or caches the instance returned by
+    /// `create`.
+    ///
+    /// The scope is not locked while `create` runs, so it may resolve other scoped instances.
+    pub fn get_or_insert_with<P: 'static, T: Send + Sync + 'static>(
+        &self,
+        create: impl FnOnce() -> Result<T, InjectError>,
+    ) -> Result<Arc<T>, InjectError> {
+        let id = TypeId::of::<P>();
+        if let Some(instance) = self.instances.lock().unwrap().get(&id) {
+            return Self::downcast(instance.as_ref());
+        }
+        let instance = Box::new(Arc::new(create()?));
+        Self::downcast(
+            self.instances
+                .lock()
+                .unwrap()
+                .entry(id)
+                .or_insert(instance)
+                .as_ref(),
+        )
+    }
+
+    fn downcast<T: 'static>(instance: &dyn Any) -> Result<Arc<T>, InjectError> {
+        instance
+            .downcast_ref::<Arc<T>>()
+            .map(Arc::clone)
+            .ok_or_else(InjectError::failed_cast)
+    }
+}
@@ -0,0 +1,233 @@
+use std::mem;
+
+use crate::bindings::Entry;
+use crate::inject::Inject;
+use crate::provider::{AsyncProvider, Provider, RefProvider};
+use crate::Container;
+use crate::InjectError;
+
+impl Container {
+    /// Create a new strict `Container`. Installing a provider for a type that already has one
+    /// installed into the container panics, rather than silently replacing it, which catches two
+    /// modules binding the same type. Children of a strict container are strict as well.
+    ///
+    /// Providers are intentionally replaced using
+    /// [`container.replace(..)`](struct.Container.html#method.replace), or
+    /// [`container.with_overrides(..)`](struct.Container.html#method.with_overrides). Providers
+    /// installed into an ancestor can still be shadowed.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// use inject::Container;
+    ///
+    /// let mut container = Container::strict();
+    /// container.install(|_: &Container| Ok(String::from("postgres://")));
+    /// container.install(|_: &Container| Ok(String::from("sqlite::memory:")));
+    /// ```
+    pub fn strict() -> Container {
+        Self {
+            strict: true,
+            ..Self::default()
+        }
+    }
+
+    /// Whether this container is [strict](struct.Container.html#method.strict).
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Install a [`Provider`](provider/trait.Provider.html) into this `Container`, unless one is
+    /// already installed for its type, in which case `InjectError::DuplicateBinding` is returned
+    /// and the installed provider is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{get, Container, InjectErrorKind};
+    ///
+    /// let mut container = Container::new();
+    ///
+    /// assert!(container.try_install(|_: &Container| Ok(5432u16)).is_ok());
+    ///
+    /// let error = container.try_install(|_: &Container| Ok(0u16)).unwrap_err();
+    ///
+    /// assert_eq!(InjectErrorKind::DuplicateBinding, error.kind());
+    /// assert_eq!("u16", error.type_name());
+    /// assert_eq!(Ok(5432), get!(&container, u16));
+    /// ```
+    pub fn try_install<T: Inject, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        &mut self,
+        provider: P,
+    ) -> Result<(), InjectError> {
+        self.try_push(Self::value_entry(None, provider))
+    }
+
+    /// Like [`container.try_install(..)`](struct.Container.html#method.try_install), for a
+    /// provider qualified by `name`.
+    pub fn try_install_named<T: Inject, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        &mut self,
+        name: &'static str,
+        provider: P,
+    ) -> Result<(), InjectError> {
+        self.try_push(Self::value_entry(Some(name), provider))
+    }
+
+    /// Like [`container.try_install(..)`](struct.Container.html#method.try_install), for a
+    /// [`RefProvider`](provider/trait.RefProvider.html).
+    pub fn try_install_ref<
+        T: ?Sized + 'static,
+        P: 'static + RefProvider<ProvidedRef = T> + Send + Sync,
+    >(
+        &mut self,
+        provider: P,
+    ) -> Result<(), InjectError> {
+        self.try_push(Self::ref_entry(None, provider))
+    }
+
+    /// Like [`container.try_install_ref(..)`](struct.Container.html#method.try_install_ref), for a
+    /// provider qualified by `name`.
+    pub fn try_install_ref_named<
+        T: ?Sized + 'static,
+        P: 'static + RefProvider<ProvidedRef = T> + Send + Sync,
+    >(
+        &mut self,
+        name: &'static str,
+        provider: P,
+    ) -> Result<(), InjectError> {
+        self.try_push(Self::ref_entry(Some(name), provider))
+    }
+
+    /// Like [`container.try_install(..)`](struct.Container.html#method.try_install), for an
+    /// [`AsyncProvider`](provider/trait.AsyncProvider.html).
+    pub fn try_install_async<
+        T: Inject,
+        P: 'static + AsyncProvider<ProvidedType = T> + Send + Sync,
+    >(
+        &mut self,
+        provider: P,
+    ) -> Result<(), InjectError> {
+        self.try_push(Self::async_entry(None, provider))
+    }
+
+    /// Like [`container.try_install_async(..)`](struct.Container.html#method.try_install_async),
+    /// for a provider qualified by `name`.
+    pub fn try_install_async_named<
+        T: Inject,
+        P: 'static + AsyncProvider<ProvidedType = T> + Send + Sync,
+    >(
+        &mut self,
+        name: &'static str,
+        provider: P,
+    ) -> Result<(), InjectError> {
+        self.try_push(Self::async_entry(Some(name), provider))
+    }
+
+    /// Install a [`Provider`](provider/trait.Provider.html) into this `Container`, intentionally
+    /// replacing the provider previously installed for its type. Unlike
+    /// [`container.install(..)`](struct.Container.html#method.install), never panics in a
+    /// [strict](struct.Container.html#method.strict) container.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use inject::{get, Container};
+    ///
+    /// let mut container = Container::strict();
+    /// container.install(|_: &Container| Ok(5432u16));
+    /// container.replace(|_: &Container| Ok(0u16));
+    ///
+    /// assert_eq!(Ok(0), get!(&container, u16));
+    /// ```
+    pub fn replace<T: Inject, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        &mut self,
+        provider: P,
+    ) {
+        self.bindings.push(Self::value_entry(None, provider));
+    }
+
+    /// Like [`container.replace(..)`](struct.Container.html#method.replace), for a provider
+    /// qualified by `name`.
+    pub fn replace_named<T: Inject, P: 'static + Provider<ProvidedType = T> + Send + Sync>(
+        &mut self,
+        name: &'static str,
+        provider: P,
+    ) {
+        self.bindings.push(Self::value_entry(Some(name), provider));
+    }
+
+    /// Like [`container.replace(..)`](struct.Container.html#method.replace), for a
+    /// [`RefProvider`](provider/trait.RefProvider.html).
+    pub fn replace_ref<
+        T: ?Sized + 'static,
+        P: 'static + RefProvider<ProvidedRef = T> + Send + Sync,
+    >(
+        &mut self,
+        provider: P,
+    ) {
+        self.bindings.push(Self::ref_entry(None, provider));
+    }
+
+    /// Like [`container.replace_ref(..)`](struct.Container.html#method.replace_ref), for a
+    /// provider qualified by `name`.
+    pub fn replace_ref_named<
+        T: ?Sized + 'static,
+        P: 'static + RefProvider<ProvidedRef = T> + Send + Sync,
+    >(
+        &mut self,
+        name: &'static str,
+        provider: P,
+    ) {
+        self.bindings.push(Self::ref_entry(Some(name), provider));
+    }
+
+    /// Like [`container.replace(..)`](struct.Container.html#method.replace), for an
+    /// [`AsyncProvider`](provider/trait.AsyncProvider.html).
+    pub fn replace_async<T: Inject, P: 'static + AsyncProvider<ProvidedType = T> + Send + Sync>(
+        &mut self,
+        provider: P,
+    ) {
+        self.bindings.push(Self::async_entry(None, provider));
+    }
+
+    /// Like [`container.replace_async(..)`](struct.Container.html#method.replace_async), for a
+    /// provider qualified by `name`.
+    pub fn replace_async_named<
+        T: Inject,
+        P: 'static + AsyncProvider<ProvidedType = T> + Send + Sync,
+    >(
+        &mut self,
+        name: &'static str,
+        provider: P,
+    ) {
+        self.bindings.push(Self::async_entry(Some(name), provider));
+    }
+
+    /// Run `configure`, letting it replace providers even if this container is strict.
+    pub(crate) fn replacing<R>(&mut self, configure: impl FnOnce(&mut Container) -> R) -> R {
+        let strict = mem::replace(&mut self.strict, false);
+        let result = configure(self);
+        self.strict = strict;
+        result
+    }
+
+    /// Append `entry`, panicking if this container is strict and `entry` replaces a provider.
+    pub(crate) fn push(&self, entry: Entry) {
+        if self.strict {
+            if let Err(err) = self.try_push(entry) {
+                panic!("{}", err);
+            }
+        } else {
+            self.bindings.push(entry);
+        }
+    }
+
+    fn try_push(&self, entry: Entry) -> Result<(), InjectError> {
+        let type_name = entry.info.type_name();
+        if self.bindings.try_push(entry) {
+            Ok(())
+        } else {
+            Err(InjectError::duplicate_binding(type_name))
+        }
+    }
+}
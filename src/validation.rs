@@ -0,0 +1,175 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::inject::Inject;
+use crate::key::Key;
+use crate::Container;
+use crate::InjectError;
+
+/// Resolves an installed binding using a container, discarding the instance. Takes the name the
+/// binding was installed under, if any.
+pub(crate) type Resolve = fn(&Container, Option<&'static str>) -> Result<(), InjectError>;
+
+impl Container {
+    /// Instantiate every eager binding, like [`singleton!(T, eager)`](macro.singleton.html),
+    /// so that failures surface at startup instead of on first use. Fails with the first error
+    /// encountered.
+    ///
+    /// Eager bindings installed into the ancestors of this container are instantiated as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// use ::inject::{container, get, inject, singleton};
+    ///
+    /// static CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// struct Pool;
+    ///
+    /// impl Pool {
+    ///     #[inject]
+    ///     fn new() -> Self {
+    ///         CONNECTIONS.fetch_add(1, Ordering::SeqCst);
+    ///         Self
+    ///     }
+    /// }
+    ///
+    /// let container = container![singleton!(Pool, eager)].build().unwrap();
+    ///
+    /// assert_eq!(1, CONNECTIONS.load(Ordering::SeqCst));
+    ///
+    /// get!(&container, Arc<Pool>).unwrap();
+    ///
+    /// assert_eq!(1, CONNECTIONS.load(Ordering::SeqCst));
+    /// ```
+    pub fn build(self) -> Result<Self, InjectError> {
+        for (key, resolve, _) in self.resolvable().into_iter().filter(|(.., eager)| *eager) {
+            resolve(&self, name_of(key))?;
+        }
+        Ok(self)
+    }
+
+    /// Resolve every binding installed into this container and its ancestors, reporting every
+    /// failure at once rather than on first use. Shadowed bindings are skipped, and sets and
+    /// maps are resolved as a whole.
+    ///
+    /// Validating invokes the providers, so [`singleton!`](macro.singleton.html)s are
+    /// instantiated by it. Bindings are resolved within a scope entered for the validation only,
+    /// so [`scoped!`](macro.scoped.html) bindings are validated as well, without leaving instances
+    /// behind. Asynchronous providers are not validated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ::inject::{container, inject, Container, InjectErrorKind};
+    ///
+    /// struct Pool;
+    ///
+    /// struct Repo;
+    ///
+    /// impl Repo {
+    ///     #[inject]
+    ///     fn new(pool: &Pool) -> Self {
+    ///         Self
+    ///     }
+    /// }
+    ///
+    /// let container = container![
+    ///     |container: &Container| Repo::inject(container),
+    ///     |_: &Container| Ok(5432u16),
+    ///     "db_url" => |container: &Container| container.get::<String>(),
+    /// ];
+    ///
+    /// let errors = container.validate().unwrap_err();
+    ///
+    /// assert_eq!(2, errors.len());
+    /// assert!(errors[0].type_name().ends_with("Pool"));
+    /// assert_eq!(InjectErrorKind::MissingProvider, errors[1].kind());
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<InjectError>> {
+        let scope = self.enter_scope();
+        let errors: Vec<_> = self
+            .resolvable()
+            .into_iter()
+            .filter_map(|(key, resolve, _)| resolve(&scope, name_of(key)).err())
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Every key bound in this container and its ancestors which can be resolved synchronously,
+    /// outermost ancestor first, in installation order, together with whether its nearest binding
+    /// is eager.
+    fn resolvable(&self) -> Vec<(Key, Resolve, bool)> {
+        let mut lineage: Vec<_> = self.lineage().collect();
+        lineage.reverse();
+        let mut resolvable: Vec<(Key, Resolve, bool)> = Vec::new();
+        let mut positions: HashMap<Key, usize> = HashMap::new();
+        for entry in lineage
+            .into_iter()
+            .flat_map(|container| container.bindings.iter())
+        {
+            let resolve = match entry.resolve {
+                Some(resolve) => resolve,
+                None => continue,
+            };
+            match positions.entry(entry.key) {
+                Entry::Occupied(position) => resolvable[*position.get()].2 = entry.eager,
+                Entry::Vacant(position) => {
+                    position.insert(resolvable.len());
+                    resolvable.push((entry.key, resolve, entry.eager));
+                }
+            }
+        }
+        resolvable
+    }
+}
+
+fn name_of(key: Key) -> Option<&'static str> {
+    match key {
+        Key::Single(_, name) | Key::Async(_, name) => name,
+        Key::Set(_) | Key::Map(..) | Key::Decorator(_) => None,
+    }
+}
+
+pub(crate) fn value<T: Inject>(
+    container: &Container,
+    name: Option<&'static str>,
+) -> Result<(), InjectError> {
+    match name {
+        Some(name) => container.get_named::<T>(name).map(drop),
+        None => container.get::<T>().map(drop),
+    }
+}
+
+pub(crate) fn reference<T: ?Sized + 'static>(
+    container: &Container,
+    name: Option<&'static str>,
+) -> Result<(), InjectError> {
+    match name {
+        Some(name) => container.get_ref_named::<T>(name).map(drop),
+        None => container.get_ref::<T>().map(drop),
+    }
+}
+
+pub(crate) fn set<T: Inject>(
+    container: &Container,
+    _: Option<&'static str>,
+) -> Result<(), InjectError> {
+    container.get_all::<T>().map(drop)
+}
+
+pub(crate) fn map<K, T>(container: &Container, _: Option<&'static str>) -> Result<(), InjectError>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    T: Inject,
+{
+    container.get_map::<K, T>().map(drop)
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use ::inject::*;
 use rstest::*;
 
-pub trait TestTrait: Inject {
+pub trait TestTrait: Inject + Send + Sync {
     fn hello(&self) -> &'static str {
         "Hello"
     }
@@ -95,6 +95,31 @@ impl ArcData {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Replicated {
+    pub primary: Data,
+    pub replica: Data,
+}
+
+impl Replicated {
+    #[inject(named(primary = "primary", replica = "replica"))]
+    pub fn new(primary: Data, replica: Data) -> Self {
+        Self { primary, replica }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collected {
+    pub data: Vec<Data>,
+}
+
+impl Collected {
+    #[inject]
+    pub fn new(data: Vec<Data>) -> Self {
+        Self { data }
+    }
+}
+
 #[fixture(a = 1)]
 pub fn data(a: isize) -> Data {
     Data::new(a)
@@ -116,17 +141,17 @@ pub fn arc_data(data: Data) -> ArcData {
 }
 
 #[fixture(data = Data { a: 2 })]
-pub fn data_provider(data: Data) -> impl Provider<ProvidedType = Data> {
+pub fn data_provider(data: Data) -> impl Provider<ProvidedType = Data> + Send + Sync {
     move |_: &_| Ok(data)
 }
 
 #[fixture]
-pub fn depends_on_data_provider() -> impl Provider<ProvidedType = DependsOnData> {
+pub fn depends_on_data_provider() -> impl Provider<ProvidedType = DependsOnData> + Send + Sync {
     move |container: &Container| Ok(DependsOnData::new(container.get()?, 0))
 }
 
 #[fixture]
-pub fn data_arc_provider(data: Data) -> impl Provider<ProvidedType = Arc<Data>> {
+pub fn data_arc_provider(data: Data) -> impl Provider<ProvidedType = Arc<Data>> + Send + Sync {
     DataArcProvider {
         data: Arc::new(data),
     }
@@ -143,3 +168,195 @@ pub fn test_trait_provider(data: Data) -> TestTraitProvider {
 pub fn depends_on_dyn(data: Data) -> DependsOnDyn {
     DependsOnDyn::new(Arc::new(data))
 }
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Egg;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chicken {
+    pub egg: Egg,
+}
+
+impl Chicken {
+    #[inject]
+    pub fn new(egg: Egg) -> Self {
+        Self { egg }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Inject)]
+pub struct DerivedData {
+    pub data: Data,
+    #[inject(named = "primary")]
+    pub primary: Data,
+    #[inject(default = 7, no_inject)]
+    pub b: isize,
+}
+
+#[fixture]
+pub fn derived_data(data: Data) -> DerivedData {
+    DerivedData {
+        data,
+        primary: Data::new(2),
+        b: 7,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Connection {
+    pub data: Data,
+}
+
+impl Connection {
+    #[inject]
+    pub async fn connect(data: Data) -> Self {
+        Self { data }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependsOnOptional {
+    pub data: Option<Data>,
+    pub b: Option<isize>,
+}
+
+impl DependsOnOptional {
+    #[inject(named(b = "b"))]
+    pub fn new(data: Option<Data>, b: Option<isize>) -> Self {
+        Self { data, b }
+    }
+}
+
+#[inject]
+pub fn optional_ref(data: Option<&Data>) -> Option<isize> {
+    data.map(|data| data.a)
+}
+
+pub trait Repo: Send + Sync {
+    fn name(&self) -> &'static str;
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Postgres;
+
+impl Repo for Postgres {
+    fn name(&self) -> &'static str {
+        "postgres"
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Sqlite;
+
+impl Repo for Sqlite {
+    fn name(&self) -> &'static str {
+        "sqlite"
+    }
+}
+
+#[fixture]
+pub fn postgres() -> Postgres {
+    Postgres
+}
+
+#[fixture]
+pub fn sqlite() -> Sqlite {
+    Sqlite
+}
+
+#[inject]
+pub fn repo_name<R>(repo: &R, data: Option<R>) -> &'static str
+where
+    R: Repo,
+{
+    data.map_or(repo.name(), |_| "both")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request(pub &'static str);
+
+pub struct Handler {
+    pub prefix: &'static str,
+}
+
+impl Handler {
+    #[inject(no_inject(request))]
+    pub fn handle(&self, data: &Data, request: Request) -> String {
+        format!("{} {} {}", self.prefix, data.a, request.0)
+    }
+
+    #[inject]
+    pub async fn handle_async(&self, data: Data) -> isize {
+        data.a
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Started {
+    pub data: Data,
+    pub started: bool,
+}
+
+impl Started {
+    #[inject(post_construct = start)]
+    pub fn new(data: Data) -> Self {
+        Self {
+            data,
+            started: false,
+        }
+    }
+
+    fn start(&mut self) {
+        self.started = true;
+    }
+}
+
+pub struct FailsToStart(pub Data);
+
+impl FailsToStart {
+    #[inject(post_construct = start)]
+    pub fn new(data: Data) -> Self {
+        Self(data)
+    }
+
+    fn start(&self) -> Result<(), String> {
+        Err(format!("port {} in use", self.0.a))
+    }
+}
+
+#[derive(Default)]
+pub struct DisposalLog(pub std::sync::Mutex<Vec<&'static str>>);
+
+pub struct Pool {
+    pub log: Arc<DisposalLog>,
+}
+
+impl Pool {
+    #[inject]
+    pub fn new(log: Arc<DisposalLog>) -> Self {
+        Self { log }
+    }
+}
+
+impl Disposable for Pool {
+    fn dispose(&self) {
+        self.log.0.lock().unwrap().push("pool");
+    }
+}
+
+pub struct Repository {
+    pub pool: Arc<Pool>,
+}
+
+impl Repository {
+    #[inject]
+    pub fn new(pool: Arc<Pool>) -> Self {
+        Self { pool }
+    }
+}
+
+impl Disposable for Repository {
+    fn dispose(&self) {
+        self.pool.log.0.lock().unwrap().push("repository");
+    }
+}
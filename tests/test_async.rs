@@ -0,0 +1,183 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ::inject::provider::async_fn;
+use ::inject::{call, container, get, inject, Container, InjectErrorKind, Provider};
+use pollster::block_on;
+use rstest::*;
+
+mod fixtures;
+use fixtures::*;
+
+#[inject]
+async fn async_func(a: Data, b: Connection) -> isize {
+    a.a + b.data.a
+}
+
+#[rstest]
+fn test_async_provider() {
+    let container = container![async async_fn(|_: &Container| Box::pin(async {
+        Ok(Data::new(2))
+    }))];
+
+    assert_eq!(Ok(Data::new(2)), block_on(get!(async &container, Data)));
+}
+
+#[rstest]
+fn test_async_provider_is_not_resolved_synchronously() {
+    let container = container![async async_fn(|_: &Container| Box::pin(async { Ok(3isize) }))];
+
+    assert_eq!(
+        InjectErrorKind::MissingProvider,
+        container.get::<isize>().unwrap_err().kind()
+    );
+}
+
+#[rstest]
+fn test_named_async_provider() {
+    let container = container![
+        async "primary" => async_fn(|_: &Container| Box::pin(async { Ok(Data::new(3)) })),
+    ];
+
+    assert_eq!(
+        Ok(Data::new(3)),
+        block_on(get!(async &container, Data, named: "primary"))
+    );
+}
+
+#[rstest]
+fn test_get_async_uses_sync_provider(data_provider: impl Provider + Send + Sync + 'static) {
+    let container = container![data_provider];
+
+    assert_eq!(Ok(Data::new(2)), block_on(get!(async &container, Data)));
+}
+
+#[rstest]
+fn test_async_construct_with_async_dependency() {
+    let container = container![async async_fn(|_: &Container| Box::pin(async { Ok(4isize) }))];
+
+    let expected = DependsOnData::new(Data::new(4), 4);
+
+    assert_eq!(
+        Ok(expected),
+        block_on(get!(async &container, DependsOnData))
+    );
+}
+
+#[rstest]
+fn test_async_constructor(data_provider: impl Provider + Send + Sync + 'static) {
+    let container = container![data_provider];
+
+    let connection = block_on(get!(async &container, Connection)).unwrap();
+
+    assert_eq!(Data::new(2), connection.data);
+}
+
+#[rstest]
+fn test_async_constructor_is_not_resolved_synchronously() {
+    let container = container![];
+
+    assert_eq!(
+        InjectErrorKind::MissingProvider,
+        container.get::<Connection>().unwrap_err().kind()
+    );
+}
+
+#[rstest]
+fn test_async_call() {
+    let container = container![];
+
+    assert_eq!(Ok(2), block_on(call!(async &container, async_func)));
+}
+
+#[rstest]
+fn test_async_call_with_kwarg() {
+    let container = container![];
+
+    let result = block_on(call!(
+        async &container,
+        async_func,
+        kwargs = { a: Data::new(5) }
+    ));
+
+    assert_eq!(Ok(6), result);
+}
+
+#[rstest]
+fn test_async_derived() {
+    let container = container![
+        "primary" => |_: &Container| Ok(Data::new(4)),
+    ];
+
+    let derived = block_on(get!(async &container, DerivedData)).unwrap();
+
+    assert_eq!(Data::new(4), derived.primary);
+    assert_eq!(7, derived.b);
+}
+
+#[rstest]
+fn test_async_construct_with_trait_object_dependency(test_trait_provider: TestTraitProvider) {
+    let container = container![test_trait_provider];
+
+    let depends_on_dyn = block_on(get!(async &container, DependsOnDyn)).unwrap();
+
+    assert_eq!("Hello", depends_on_dyn.test_trait.hello());
+}
+
+#[rstest]
+fn test_async_cycle_is_detected() {
+    let container = container![async async_fn(|container: &Container| Box::pin(async move {
+        container.get_async::<isize>().await
+    }))];
+
+    let error = block_on(get!(async &container, isize)).unwrap_err();
+
+    assert_eq!(InjectErrorKind::Cycle, error.kind());
+}
+
+#[rstest]
+fn test_async_optional() {
+    let container = container![async async_fn(|_: &Container| Box::pin(async { Ok(3isize) }))];
+
+    assert_eq!(Ok(Some(3)), block_on(get!(async &container, Option<isize>)));
+    assert_eq!(Ok(Some(0)), block_on(get!(async &container, Option<usize>)));
+    assert_eq!(
+        Ok(None),
+        block_on(get!(async &container, Option<usize>, create: false))
+    );
+}
+
+#[rstest]
+fn test_async_container_expression_is_evaluated_once() {
+    let evaluated = AtomicUsize::new(0);
+    let make = || {
+        evaluated.fetch_add(1, Ordering::SeqCst);
+        container![]
+    };
+
+    assert!(block_on(get!(async &make(), Data)).is_ok());
+    assert_eq!(1, evaluated.swap(0, Ordering::SeqCst));
+
+    assert!(block_on(get!(async &make(), Option<Data>)).is_ok());
+    assert_eq!(1, evaluated.swap(0, Ordering::SeqCst));
+
+    assert!(block_on(get!(async &make(), Option<usize>, create: false)).is_ok());
+    assert_eq!(1, evaluated.load(Ordering::SeqCst));
+}
+
+#[rstest]
+fn test_async_construct_with_kwargs() {
+    let container = container![];
+
+    let connection = block_on(get!(async &container, Connection, kwargs = { data: Data::new(9) }));
+
+    assert_eq!(Ok(Connection { data: Data::new(9) }), connection);
+}
+
+#[rstest]
+fn test_post_construct_is_called_when_injected_asynchronously() {
+    let container = container![];
+
+    let started = block_on(get!(async &container, Started)).unwrap();
+
+    assert!(started.started);
+}
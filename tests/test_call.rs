@@ -12,6 +12,11 @@ fn func_with_args(a: Data, b: Data) -> isize {
     a.a + b.a
 }
 
+#[inject]
+fn greet(depends_on_dyn: DependsOnDyn) -> &'static str {
+    depends_on_dyn.test_trait.hello()
+}
+
 #[rstest]
 fn test_call() {
     let container = container![];
@@ -40,3 +45,135 @@ fn test_call_with_injectable_args_provider(data: Data) {
     let a = call!(&container, func_with_args, kwargs = { b: Data::new(1) }).unwrap();
     assert_eq!(a, 4)
 }
+
+#[rstest]
+fn test_call_with_trait_object_kwarg(depends_on_dyn: DependsOnDyn) {
+    let container = container![];
+    let greeting = call!(&container, greet, kwargs = { depends_on_dyn }).unwrap();
+    assert_eq!("Hello", greeting)
+}
+
+#[rstest]
+fn test_call_generic_function_with_turbofish(postgres: Postgres, sqlite: Sqlite) {
+    let container = container![ref Box::new(postgres), ref Box::new(sqlite)];
+
+    assert_eq!(Ok("postgres"), call!(&container, repo_name::<Postgres>));
+    assert_eq!(Ok("sqlite"), call!(&container, repo_name::<Sqlite>));
+}
+
+#[rstest]
+fn test_call_generic_function_with_turbofish_and_kwargs() {
+    let container = container![ref Box::new(Sqlite)];
+
+    let name = call!(&container, repo_name::<Sqlite>, kwargs = { data: Some(Sqlite) });
+
+    assert_eq!(Ok("both"), name);
+}
+
+mod handlers {
+    use ::inject::inject;
+
+    use super::fixtures::{Data, Repo};
+
+    #[inject]
+    pub fn create(data: Data) -> isize {
+        data.a
+    }
+
+    #[inject]
+    pub fn describe<R: Repo>(repo: &R, data: Data) -> String {
+        format!("{} {}", repo.name(), data.a)
+    }
+
+    pub mod admin {
+        use ::inject::inject;
+
+        use super::Data;
+
+        #[inject]
+        pub fn create(data: &Data) -> isize {
+            -data.a
+        }
+    }
+}
+
+#[rstest]
+fn test_call_path_qualified_functions_sharing_a_name() {
+    let container = container![ref Box::new(Data::new(3))];
+
+    assert_eq!(Ok(1), call!(&container, handlers::create));
+    assert_eq!(Ok(-3), call!(&container, handlers::admin::create));
+    assert_eq!(
+        Ok(5),
+        call!(&container, self::handlers::create, kwargs = { data: Data::new(5) })
+    );
+}
+
+mod routes {
+    use ::inject::{call, Container, InjectError};
+
+    use super::fixtures::Data;
+
+    pub fn dispatch(container: &Container) -> Result<isize, InjectError> {
+        let data = Data::new(2);
+        let created = call!(container, super::handlers::create)?;
+        let removed = call!(container, crate::handlers::admin::create, kwargs = { data: &data })?;
+        Ok(created + removed)
+    }
+}
+
+#[rstest]
+fn test_call_path_crossing_modules() {
+    let container = container![|_: &_| Ok(Data::new(7))];
+
+    assert_eq!(Ok(5), routes::dispatch(&container));
+}
+
+#[rstest]
+fn test_call_path_qualified_generic_function() {
+    let container = container![ref Box::new(Sqlite)];
+
+    assert_eq!(
+        Ok(String::from("sqlite 1")),
+        call!(&container, handlers::describe::<Sqlite>)
+    );
+}
+
+#[rstest]
+fn test_call_method() {
+    let container = container![ref Box::new(Data::new(3)), |_: &_| Ok(Request("get"))];
+    let handler = Handler { prefix: "handled" };
+
+    assert_eq!(
+        Ok("handled 3 get".to_string()),
+        call!(&container, handler.handle)
+    );
+}
+
+#[rstest]
+fn test_call_method_with_kwargs() {
+    let container = container![ref Box::new(Data::new(3))];
+    let handler = std::sync::Arc::new(Handler { prefix: "handled" });
+    let request = Request("post");
+    let data = Data::new(4);
+
+    assert_eq!(
+        Ok("handled 3 post".to_string()),
+        call!(&container, handler.handle, kwargs = { request })
+    );
+    assert_eq!(
+        Ok("handled 4 put".to_string()),
+        call!(&container, handler.handle, kwargs = { data: &data, request: Request("put") })
+    );
+}
+
+#[rstest]
+fn test_call_async_method() {
+    let container = container![];
+    let handler = Handler { prefix: "handled" };
+
+    assert_eq!(
+        Ok(1),
+        pollster::block_on(call!(async &container, handler.handle_async))
+    );
+}
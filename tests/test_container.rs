@@ -1,6 +1,16 @@
-use ::inject::{container, get, Container, InjectError, Provider};
+use ::inject::graph::{Dependency, Lifetime, ProviderKind};
+use ::inject::module::{Module, Modules};
+use ::inject::provider::async_fn;
+use ::inject::{
+    container, get, implements, scoped, singleton, Container, InjectError, InjectErrorKind,
+    Provider,
+};
+use std::any::type_name;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
+use pollster::block_on;
 use rstest::*;
 
 mod fixtures;
@@ -8,10 +18,10 @@ use fixtures::*;
 
 #[rstest]
 fn test_get_without_provider_installed_errors() {
-    let expected = Err(InjectError::MissingProvider);
+    let expected = Err(InjectErrorKind::MissingProvider);
     let container = Container::default();
 
-    let provided = container.get::<Data>();
+    let provided = container.get::<Data>().map_err(|err| err.kind());
 
     assert_eq!(provided, expected)
 }
@@ -19,7 +29,7 @@ fn test_get_without_provider_installed_errors() {
 #[rstest(data(2))]
 fn test_get_with_provider_installed_returns_implementation(
     data: Data,
-    data_provider: impl Provider + 'static,
+    data_provider: impl Provider + Send + Sync + 'static,
 ) {
     let mut container = Container::new();
     container.install(data_provider);
@@ -69,7 +79,7 @@ fn test_get_macro_resolves_default_in_nested_provider_when_provider_missing(
 }
 
 #[rstest]
-fn test_get_macro_resolves_nested_provider(data_provider: impl Provider + 'static) {
+fn test_get_macro_resolves_nested_provider(data_provider: impl Provider + Send + Sync + 'static) {
     let expected = DependsOnData {
         data: Data::new(2),
         b: 0,
@@ -82,8 +92,8 @@ fn test_get_macro_resolves_nested_provider(data_provider: impl Provider + 'stati
 
 #[rstest]
 fn test_get_macro_resolves_nested_provider_with_nested_provider(
-    data_provider: impl Provider + 'static,
-    depends_on_data_provider: impl Provider + 'static,
+    data_provider: impl Provider + Send + Sync + 'static,
+    depends_on_data_provider: impl Provider + Send + Sync + 'static,
 ) {
     let expected = GenericData::new(DependsOnData::new(Data::new(2), 0));
 
@@ -95,7 +105,9 @@ fn test_get_macro_resolves_nested_provider_with_nested_provider(
 }
 
 #[rstest]
-fn test_get_macro_resolves_dyn_provider(test_trait_provider: impl Provider + 'static) {
+fn test_get_macro_resolves_dyn_provider(
+    test_trait_provider: impl Provider + Send + Sync + 'static,
+) {
     let container = container![test_trait_provider];
 
     let provided = get!(&container, DependsOnDyn).unwrap();
@@ -111,3 +123,1056 @@ fn test_get_macro_resolves_reference(data: Data) {
 
     assert_eq!(provided, &data)
 }
+
+#[rstest]
+fn test_named_providers_do_not_replace_each_other() {
+    let container = container![
+        "primary" => |_: &_| Ok(Data::new(1)),
+        "replica" => |_: &_| Ok(Data::new(2)),
+        |_: &_| Ok(Data::new(3)),
+    ];
+
+    assert_eq!(Ok(Data::new(1)), container.get_named("primary"));
+    assert_eq!(Ok(Data::new(2)), container.get_named("replica"));
+    assert_eq!(Ok(Data::new(3)), container.get());
+}
+
+#[rstest]
+fn test_get_macro_resolves_named_reference(data: Data) {
+    let container = container![ref "primary" => Box::new(data)];
+
+    let provided = get!(&container, &Data, named: "primary").unwrap();
+
+    assert_eq!(provided, &data)
+}
+
+#[rstest]
+fn test_get_macro_does_not_create_named_value() {
+    let container = container![];
+
+    let provided = get!(&container, Data, named: "primary").map_err(|err| err.kind());
+
+    assert_eq!(provided, Err(InjectErrorKind::MissingProvider))
+}
+
+#[rstest]
+fn test_set_contributions_accumulate_in_installation_order() {
+    let mut container = Container::new();
+    container.install_into_set(|_: &_| Ok(Data::new(1)));
+    container.install_into_set(|_: &_| Ok(Data::new(2)));
+    container.install_into_set(|_: &_| Ok(Data::new(3)));
+
+    let provided = get!(&container, Vec<Data>).unwrap();
+
+    assert_eq!(provided, vec![Data::new(1), Data::new(2), Data::new(3)])
+}
+
+#[rstest]
+fn test_get_all_without_contributions_is_empty() {
+    let container = Container::new();
+
+    assert_eq!(container.get_all::<Data>(), Ok(vec![]))
+}
+
+#[rstest]
+fn test_installed_vec_provider_takes_precedence_over_set(data: Data) {
+    let mut container = container![move |_: &_| Ok(vec![data])];
+    container.install_into_set(|_: &_| Ok(Data::new(2)));
+
+    let provided = get!(&container, Vec<Data>).unwrap();
+
+    assert_eq!(provided, vec![data])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Command {
+    Create,
+    Delete,
+}
+
+#[rstest]
+fn test_map_contributions_are_keyed() {
+    let mut container = Container::new();
+    container.install_into_map(Command::Create, |_: &_| Ok(Data::new(1)));
+    container.install_into_map(Command::Delete, |_: &_| Ok(Data::new(2)));
+
+    let provided = get!(&container, HashMap<Command, Data>).unwrap();
+
+    assert_eq!(provided.len(), 2);
+    assert_eq!(provided[&Command::Create], Data::new(1));
+    assert_eq!(provided[&Command::Delete], Data::new(2));
+}
+
+#[rstest]
+fn test_lazy_map_contributions_are_provided_on_call() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let mut container = Container::new();
+    container.install_into_map("create", move |_: &_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Ok(Data::new(1))
+    });
+
+    let provided = get!(
+        &container,
+        HashMap<&str, Box<dyn Fn() -> Result<Data, InjectError>>>
+    )
+    .unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(provided["create"](), Ok(Data::new(1)));
+    assert_eq!(provided["create"](), Ok(Data::new(1)));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[rstest]
+fn test_child_resolves_providers_installed_into_parent_after_creation(data: Data) {
+    let mut container = Container::new();
+    let child = container.create_child();
+
+    container.install(move |_: &_| Ok(data));
+    container.install_ref(Box::new(data));
+
+    assert_eq!(child.get::<Data>(), Ok(data));
+    assert_eq!(get!(&child, &Data), Ok(&data));
+}
+
+#[rstest]
+fn test_child_providers_shadow_parent_providers() {
+    let container = container![|_: &_| Ok(Data::new(1))];
+    let mut child = container.create_child();
+    child.install(|_: &_| Ok(Data::new(2)));
+
+    assert_eq!(child.get::<Data>(), Ok(Data::new(2)));
+    assert_eq!(container.get::<Data>(), Ok(Data::new(1)));
+}
+
+#[rstest]
+fn test_parent_singleton_is_shared_by_children() {
+    let container = container![singleton!(Data)];
+    let first_child = container.create_child();
+    let second_child = container.create_child().create_child();
+
+    let from_first = get!(&first_child, Arc<Data>).unwrap();
+    let from_second = get!(&second_child, Arc<Data>).unwrap();
+
+    assert!(Arc::ptr_eq(&from_first, &from_second));
+    assert!(Arc::ptr_eq(
+        &from_first,
+        &get!(&container, Arc<Data>).unwrap()
+    ));
+}
+
+#[rstest]
+fn test_parent_providers_resolve_using_parent() {
+    let container = container![depends_on_data_provider()];
+    let mut child = container.create_child();
+    child.install(|_: &_| Ok(Data::new(2)));
+
+    let provided = child.get::<DependsOnData>().map_err(|err| err.kind());
+
+    assert_eq!(provided, Err(InjectErrorKind::MissingProvider))
+}
+
+#[rstest]
+fn test_child_set_contributions_follow_parent_contributions() {
+    let mut container = Container::new();
+    container.install_into_set(|_: &_| Ok(Data::new(1)));
+    let mut child = container.create_child();
+    child.install_into_set(|_: &_| Ok(Data::new(3)));
+    container.install_into_set(|_: &_| Ok(Data::new(2)));
+
+    let provided = get!(&child, Vec<Data>).unwrap();
+
+    assert_eq!(provided, vec![Data::new(1), Data::new(2), Data::new(3)])
+}
+
+#[rstest]
+fn test_scoped_set_contributions_are_cached_per_scope() {
+    let mut container = Container::new();
+    container.install_into_set(scoped!(Data));
+    let scope = container.enter_scope();
+    let other_scope = container.enter_scope();
+
+    let first = get!(&scope, Vec<Arc<Data>>).unwrap();
+    let second = get!(&scope, Vec<Arc<Data>>).unwrap();
+    let other = get!(&other_scope, Vec<Arc<Data>>).unwrap();
+
+    assert!(Arc::ptr_eq(&first[0], &second[0]));
+    assert!(!Arc::ptr_eq(&first[0], &other[0]));
+}
+
+#[rstest]
+fn test_scoped_map_contributions_are_cached_per_scope() {
+    let mut container = Container::new();
+    container.install_into_map(Command::Create, scoped!(Data));
+    let scope = container.enter_scope();
+
+    let provided = get!(&scope, HashMap<Command, Arc<Data>>).unwrap();
+    let lazy = get!(
+        &scope,
+        HashMap<Command, Box<dyn Fn() -> Result<Arc<Data>, InjectError>>>
+    )
+    .unwrap();
+
+    assert!(Arc::ptr_eq(
+        &provided[&Command::Create],
+        &lazy[&Command::Create]().unwrap()
+    ));
+}
+
+#[rstest]
+fn test_cycle_through_set_contribution_is_detected() {
+    let mut container = Container::new();
+    container
+        .install_into_set(|container: &Container| get!(container, Vec<Data>).map(|_| Data::new(1)));
+
+    match get!(&container, Vec<Data>).unwrap_err() {
+        InjectError::Cycle { cycle, .. } => assert_eq!(
+            cycle,
+            [
+                std::any::type_name::<Vec<Data>>(),
+                std::any::type_name::<Data>(),
+                std::any::type_name::<Vec<Data>>()
+            ]
+        ),
+        error => panic!("expected a cycle, got {}", error),
+    }
+}
+
+#[rstest]
+fn test_clone_does_not_share_later_installs() {
+    let container = Container::new();
+    let mut clone = container.clone();
+    clone.install(|_: &_| Ok(Data::new(2)));
+
+    assert_eq!(clone.get::<Data>(), Ok(Data::new(2)));
+    assert_eq!(
+        container.get::<Data>().map_err(|err| err.kind()),
+        Err(InjectErrorKind::MissingProvider)
+    );
+}
+
+#[rstest]
+fn test_container_is_shared_between_threads(data: Data) {
+    let container = Arc::new(container![singleton!(Data), move |_: &_| Ok(data)]);
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let container = Arc::clone(&container);
+            std::thread::spawn(move || {
+                let depends_on_data = get!(&container, DependsOnData).unwrap();
+                let shared = get!(&container, Arc<Data>).unwrap();
+                (depends_on_data, shared)
+            })
+        })
+        .collect();
+
+    let first = get!(&container, Arc<Data>).unwrap();
+    for worker in workers {
+        let (depends_on_data, shared) = worker.join().unwrap();
+        assert_eq!(depends_on_data.data, data);
+        assert!(Arc::ptr_eq(&first, &shared));
+    }
+}
+
+#[rstest]
+fn test_missing_provider_error_has_resolution_path() {
+    let container = container!["primary" => |_: &_| Ok(Data::new(1))];
+
+    let error = get!(&container, Replicated).unwrap_err();
+
+    assert_eq!(error.kind(), InjectErrorKind::MissingProvider);
+    assert_eq!(error.type_name(), std::any::type_name::<Data>());
+    assert_eq!(
+        error.path(),
+        [
+            std::any::type_name::<Replicated>(),
+            std::any::type_name::<Data>()
+        ]
+    );
+}
+
+#[rstest]
+fn test_provider_error_is_kept_as_source() {
+    let container = container![|_: &_| "data"
+        .parse::<isize>()
+        .map(Data::new)
+        .map_err(InjectError::provider_failed)];
+
+    let error = get!(&container, DependsOnData).unwrap_err();
+
+    assert_eq!(error.kind(), InjectErrorKind::ProviderFailed);
+    assert_eq!(
+        error.path(),
+        [
+            std::any::type_name::<DependsOnData>(),
+            std::any::type_name::<Data>()
+        ]
+    );
+    assert_eq!(
+        std::error::Error::source(&error).unwrap().to_string(),
+        "invalid digit found in string"
+    );
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "provider for `{}` failed (resolving {} -> {})",
+            std::any::type_name::<Data>(),
+            std::any::type_name::<DependsOnData>(),
+            std::any::type_name::<Data>()
+        )
+    );
+}
+
+#[rstest]
+fn test_get_macro_does_not_create_value_when_provider_fails() {
+    let container =
+        container![|_: &_| -> Result<Data, _> { Err(InjectError::provider_failed("unavailable")) }];
+
+    let provided = get!(&container, Data).map_err(|err| err.kind());
+
+    assert_eq!(provided, Err(InjectErrorKind::ProviderFailed))
+}
+
+#[rstest]
+fn test_cycle_is_detected() {
+    let container = container![|container: &Container| get!(container, Chicken).map(|_| Egg)];
+
+    let error = get!(&container, Chicken).unwrap_err();
+
+    assert_eq!(error.kind(), InjectErrorKind::Cycle);
+    assert_eq!(error.type_name(), std::any::type_name::<Chicken>());
+    match error {
+        InjectError::Cycle { cycle, .. } => assert_eq!(
+            cycle,
+            [
+                std::any::type_name::<Chicken>(),
+                std::any::type_name::<Egg>(),
+                std::any::type_name::<Chicken>()
+            ]
+        ),
+        error => panic!("expected a cycle, got {}", error),
+    }
+}
+
+#[rstest]
+fn test_named_provider_depending_on_unnamed_is_not_a_cycle() {
+    let container = container![
+        |_: &_| Ok(Data::new(1)),
+        "primary" => |container: &Container| get!(container, Data).map(|data| Data::new(data.a + 1)),
+    ];
+
+    let provided = get!(&container, Data, named: "primary");
+
+    assert_eq!(provided, Ok(Data::new(2)))
+}
+
+#[rstest]
+fn test_provider_resolving_type_using_other_container_is_not_a_cycle() {
+    let other = container![|_: &Container| Ok(1usize)];
+    let container = container![move |_: &Container| get!(&other, usize).map(|a| a + 1)];
+
+    assert_eq!(Ok(2), get!(&container, usize));
+}
+
+#[rstest]
+fn test_child_provider_delegating_to_parent_is_not_a_cycle() {
+    let parent = Arc::new(container![|_: &Container| Ok(1usize)]);
+    let mut child = parent.create_child();
+    child.install(move |_: &Container| get!(&parent, usize).map(|a| a + 1));
+
+    assert_eq!(Ok(2), get!(&child, usize));
+}
+
+#[rstest]
+fn test_bound_trait_object_resolves_implementation() {
+    let container = container![dyn TestTrait => Data];
+
+    let provided = get!(&container, DependsOnDyn).unwrap();
+
+    assert_eq!(provided.test_trait.hello(), "Hello");
+    assert_eq!(
+        get!(&container, Box<dyn TestTrait>).unwrap().hello(),
+        "Hello"
+    );
+    assert_eq!(get!(&container, &dyn TestTrait).unwrap().hello(), "Hello");
+}
+
+impl TestTrait for DependsOnData {
+    fn hello(&self) -> &'static str {
+        "Depends on data"
+    }
+}
+
+implements!(dyn TestTrait => DependsOnData);
+
+#[rstest]
+fn test_bind_resolves_implementation_using_inject() {
+    let mut container = container![|_: &Container| Ok(3isize)];
+    container.bind::<dyn TestTrait, DependsOnData>();
+
+    let boxed = get!(&container, Box<dyn TestTrait>).unwrap();
+    let arc = get!(&container, Arc<dyn TestTrait>).unwrap();
+    let reference = get!(&container, &dyn TestTrait).unwrap();
+
+    assert_eq!(boxed.hello(), "Depends on data");
+    assert_eq!(arc.hello(), "Depends on data");
+    assert_eq!(reference.hello(), "Depends on data");
+}
+
+#[rstest]
+fn test_bound_trait_object_reference_is_created_once() {
+    let created = Arc::new(AtomicUsize::new(0));
+    let mut container = Container::new();
+    container.bind_with::<dyn TestTrait>({
+        let created = Arc::clone(&created);
+        move |_: &_| {
+            created.fetch_add(1, Ordering::SeqCst);
+            Ok(Box::new(Data::new(1)))
+        }
+    });
+
+    let first = get!(&container, &dyn TestTrait).unwrap();
+    let second = get!(&container, &dyn TestTrait).unwrap();
+
+    assert!(std::ptr::eq(first, second));
+    assert_eq!(created.load(Ordering::SeqCst), 1);
+}
+
+struct DataModule;
+
+impl Module for DataModule {
+    fn configure(&self, binder: &mut Container) {
+        binder.install(|_: &Container| Ok(Data::new(3)));
+        binder.install_named("port", |_: &Container| Ok(5432usize));
+    }
+}
+
+struct DependsOnDataModule;
+
+impl Module for DependsOnDataModule {
+    fn configure(&self, binder: &mut Container) {
+        binder.install(|_: &Container| Ok(4isize));
+    }
+}
+
+#[rstest]
+fn test_install_module() {
+    let mut container = Container::new();
+    container.install_module(DataModule);
+
+    assert_eq!(Ok(Data::new(3)), get!(&container, Data));
+    assert_eq!(Ok(5432), get!(&container, usize, named: "port"));
+}
+
+#[rstest]
+fn test_container_macro_installs_modules() {
+    let container = container![module DataModule, module DependsOnDataModule];
+
+    let depends_on_data = get!(&container, DependsOnData).unwrap();
+
+    assert_eq!(DependsOnData::new(Data::new(3), 4), depends_on_data);
+}
+
+#[rstest]
+fn test_container_macro_accepts_provider_named_module() {
+    let module = |_: &Container| Ok(4isize);
+    let container = container![module];
+
+    assert_eq!(Ok(4), get!(&container, isize));
+}
+
+#[rstest]
+fn test_modules_override_with_replaces_bindings() {
+    let modules = Modules::new()
+        .with(DataModule)
+        .with(DependsOnDataModule)
+        .override_with(|binder: &mut Container| {
+            binder.install(|_: &Container| Ok(Data::new(7)));
+        });
+    let container = container![module modules];
+
+    assert_eq!(Ok(Data::new(7)), get!(&container, Data));
+    assert_eq!(Ok(5432), get!(&container, usize, named: "port"));
+    assert_eq!(Ok(4), get!(&container, isize));
+}
+
+#[rstest]
+fn test_build_instantiates_eager_singletons() {
+    let created = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&created);
+    let container = container![
+        singleton!(DependsOnData, eager),
+        singleton!(Data),
+        move |_: &Container| Ok(counter.fetch_add(1, Ordering::SeqCst) as isize),
+    ];
+
+    let container = container.build().unwrap();
+
+    assert_eq!(2, created.load(Ordering::SeqCst));
+    assert_eq!(Ok(1), get!(&container, Arc<DependsOnData>).map(|it| it.b));
+    assert_eq!(2, created.load(Ordering::SeqCst));
+}
+
+#[rstest]
+fn test_build_fails_on_eager_singleton_failure() {
+    let container = container![singleton!(DependsOnData, eager), |_: &Container| Err::<
+        isize,
+        _,
+    >(
+        InjectError::missing_provider()
+    ),];
+
+    let error = container.build().err().unwrap();
+
+    assert_eq!(InjectErrorKind::MissingProvider, error.kind());
+    assert!(error.path()[0].ends_with("Arc<test_container::fixtures::DependsOnData>"));
+}
+
+#[rstest]
+fn test_validate_passes_when_every_binding_resolves(data: Data) {
+    let mut container = container![move |_: &_| Ok(data), ref Box::new(5u16)];
+    container.install_into_set(|_: &Container| Ok("users"));
+
+    assert_eq!(Ok(()), container.validate());
+}
+
+#[rstest]
+fn test_validate_reports_every_failure() {
+    let mut container = container![
+        |container: &Container| Ok(DependsOnDyn::new(container.get()?)),
+        "port" => |container: &Container| container.get::<u16>(),
+    ];
+    container.install_into_set(|container: &Container| container.get::<usize>());
+
+    let errors = container.validate().unwrap_err();
+    let kinds: Vec<_> = errors.iter().map(InjectError::kind).collect();
+
+    assert_eq!(vec![InjectErrorKind::MissingProvider; 3], kinds);
+    assert!(errors[0].type_name().ends_with("TestTrait>"));
+    assert!(errors[1].type_name().ends_with("u16"));
+    assert!(errors[2].type_name().ends_with("usize"));
+}
+
+#[rstest]
+fn test_validate_skips_shadowed_bindings(data: Data) {
+    let container = container![|_: &Container| Err::<Data, _>(InjectError::missing_provider())];
+    let mut child = container.create_child();
+    child.install(move |_: &Container| Ok(data));
+
+    assert!(container.validate().is_err());
+    assert_eq!(Ok(()), child.validate());
+}
+
+#[rstest]
+fn test_validate_resolves_scoped_bindings_in_a_temporary_scope() {
+    let container = container![scoped!(Data), scoped!(DependsOnData)];
+
+    assert_eq!(Ok(()), container.validate());
+    assert_eq!(
+        get!(&container, Arc<Data>).map_err(|err| err.kind()),
+        Err(InjectErrorKind::MissingScope)
+    );
+}
+
+#[rstest]
+fn test_build_uses_eagerness_of_nearest_binding() {
+    let created = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&created);
+    let container = container![singleton!(DependsOnData, eager)];
+    let mut child = container.create_child();
+    child.install(singleton!(DependsOnData));
+    child.install(move |_: &Container| Ok(counter.fetch_add(1, Ordering::SeqCst) as isize));
+
+    child.build().unwrap();
+
+    assert_eq!(0, created.load(Ordering::SeqCst));
+}
+
+#[rstest]
+fn test_graph_lists_singleton_dependencies(data: Data) {
+    let container = container![singleton!(DependsOnData), move |_: &_| Ok(data)];
+
+    let graph = container.graph();
+    let providers = graph.providers();
+
+    assert_eq!(2, providers.len());
+    assert_eq!(type_name::<Arc<DependsOnData>>(), providers[0].type_name());
+    assert_eq!(
+        [Dependency::of::<Data>(None), Dependency::of::<isize>(None)],
+        providers[0].dependencies()
+    );
+    assert_eq!(ProviderKind::Value, providers[1].kind());
+    assert_eq!(vec![Dependency::of::<isize>(None)], graph.unbound());
+}
+
+#[rstest]
+fn test_graph_skips_shadowed_providers_and_merges_sets() {
+    let mut container = container![|_: &Container| Ok(1usize)];
+    container.install_into_set(singleton!(Data));
+    let mut child = container.create_child();
+    child.install(|_: &Container| Ok(2usize));
+    child.install_into_set(singleton!(Data));
+
+    let graph = child.graph();
+    let providers = graph.providers();
+
+    assert_eq!(2, providers.len());
+    assert_eq!(type_name::<Vec<Arc<Data>>>(), providers[1].type_name());
+    assert_eq!(ProviderKind::Set, providers[1].kind());
+    assert_eq!(2, providers[1].dependencies().len());
+}
+
+#[rstest]
+fn test_graph_to_dot() {
+    let container = container![singleton!(DependsOnData), "port" => |_: &Container| Ok(1isize)];
+
+    let expected = format!(
+        "digraph inject {{\n    \
+             p0 [label=\"{} (value)\"];\n    \
+             p1 [label=\"isize \\\"port\\\" (value)\"];\n    \
+             u0 [label=\"{}\", style=dashed];\n    \
+             u1 [label=\"isize\", style=dashed];\n    \
+             p0 -> u0;\n    \
+             p0 -> u1;\n\
+         }}\n",
+        type_name::<Arc<DependsOnData>>(),
+        type_name::<Data>()
+    );
+
+    assert_eq!(expected, container.graph().to_dot());
+}
+
+#[rstest]
+fn test_graph_to_json() {
+    let container = container![ref "port" => Box::new(1u16), singleton!(Data)];
+
+    let expected = format!(
+        "{{\"providers\":[\
+             {{\"type\":\"&u16\",\"name\":\"port\",\"kind\":\"reference\",\"lifetime\":\"singleton\",\"dependencies\":[]}},\
+             {{\"type\":\"{}\",\"name\":null,\"kind\":\"value\",\"lifetime\":\"singleton\",\"dependencies\":[\
+                 {{\"type\":\"isize\",\"name\":null}}\
+             ]}}\
+         ]}}",
+        type_name::<Arc<Data>>()
+    );
+
+    assert_eq!(expected, container.graph().to_json());
+}
+
+#[rstest]
+fn test_override_provider_leaves_container_untouched(data: Data) {
+    let container = container![move |_: &_| Ok(data)];
+
+    let overridden = container.override_provider(|_: &Container| Ok(Data::new(9)));
+
+    assert_eq!(Ok(Data::new(9)), get!(&overridden, Data));
+    assert_eq!(Ok(data), get!(&container, Data));
+}
+
+#[rstest]
+fn test_with_overrides_adds_providers_and_set_members() {
+    let mut container = Container::new();
+    container.install_into_set(|_: &Container| Ok("users"));
+
+    let mut overridden = container.with_overrides(|container| {
+        container.install_into_set(|_: &Container| Ok("orders"));
+        container.install(|_: &Container| Ok(1usize));
+    });
+    overridden.install_named("port", |_: &Container| Ok(2usize));
+
+    assert_eq!(Ok(vec!["users", "orders"]), get!(&overridden, Vec<&str>));
+    assert_eq!(Ok(2), get!(&overridden, usize, named: "port"));
+
+    assert_eq!(Ok(vec!["users"]), get!(&container, Vec<&str>));
+    assert!(get!(&container, usize, create: false).is_err());
+    assert!(get!(&container, usize, named: "port").is_err());
+}
+
+#[rstest]
+fn test_nested_overrides() {
+    let container = container![|_: &Container| Ok(1usize)];
+
+    let first = container.override_provider(|_: &Container| Ok(2usize));
+    let second = first.override_provider(|_: &Container| Ok(3usize));
+
+    assert_eq!(Ok(3), get!(&second, usize));
+    assert_eq!(Ok(2), get!(&first, usize));
+    assert_eq!(Ok(1), get!(&container, usize));
+}
+
+#[rstest]
+fn test_overrides_are_not_seen_by_children() {
+    let container = container![|_: &Container| Ok(1isize)];
+    let child = container.create_child();
+
+    let overridden = container.override_provider(|_: &Container| Ok(2isize));
+
+    assert_eq!(
+        Ok(DependsOnData::new(Data::new(2), 2)),
+        get!(&overridden, DependsOnData)
+    );
+    assert_eq!(
+        Ok(DependsOnData::new(Data::new(1), 1)),
+        get!(&child, DependsOnData)
+    );
+}
+
+#[rstest]
+fn test_overrides_reach_providers_of_the_container() {
+    let container = container![|_: &Container| Ok(1isize), |container: &Container| {
+        DependsOnData::inject(container)
+    },];
+
+    let overridden = container.override_provider(|_: &Container| Ok(2isize));
+
+    assert_eq!(
+        Ok(DependsOnData::new(Data::new(2), 2)),
+        get!(&overridden, DependsOnData)
+    );
+    assert_eq!(
+        Ok(DependsOnData::new(Data::new(1), 1)),
+        get!(&container, DependsOnData)
+    );
+}
+
+#[rstest]
+fn test_overrides_do_not_reach_cached_singletons() {
+    let container = container![|_: &Container| Ok(1isize), singleton!(DependsOnData)];
+
+    let overridden = container.override_provider(|_: &Container| Ok(2isize));
+
+    let cached = get!(&overridden, Arc<DependsOnData>).unwrap();
+    assert_eq!(DependsOnData::new(Data::new(1), 1), *cached);
+    assert!(Arc::ptr_eq(
+        &cached,
+        &get!(&container, Arc<DependsOnData>).unwrap()
+    ));
+}
+
+#[rstest]
+fn test_container_macro_installs_overrides_last() {
+    let container = container![
+        override |_: &Container| Ok(Data::new(7)),
+        module DataModule,
+        override "port" => |_: &Container| Ok(80usize),
+    ];
+
+    assert_eq!(Ok(Data::new(7)), get!(&container, Data));
+    assert_eq!(Ok(80), get!(&container, usize, named: "port"));
+}
+
+#[rstest]
+fn test_has_looks_up_ancestors(data: Data) {
+    let container = container![move |_: &_| Ok(data), ref Box::new(data)];
+    let child = container.create_child();
+
+    assert!(child.has::<Data>());
+    assert!(child.has_ref::<Data>());
+    assert!(!child.has::<isize>());
+    assert!(!child.has_ref::<isize>());
+}
+
+#[rstest]
+fn test_remove_falls_back_to_parent() {
+    let container = container![|_: &Container| Ok(1usize)];
+    let mut child = container.create_child();
+    child.install(|_: &Container| Ok(2usize));
+
+    assert!(child.remove::<usize>());
+    assert!(!child.remove::<usize>());
+    assert_eq!(Ok(1), get!(&child, usize));
+}
+
+#[rstest]
+fn test_remove_ref_keeps_value_provider(data: Data) {
+    let mut container = container![move |_: &_| Ok(data), ref Box::new(data)];
+
+    assert!(container.remove_ref::<Data>());
+    assert!(!container.has_ref::<Data>());
+    assert_eq!(Ok(data), get!(&container, Data));
+}
+
+#[rstest]
+fn test_named_providers_are_looked_up_and_removed_by_name(data: Data) {
+    let mut container = container![
+        "primary" => move |_: &_| Ok(data),
+        ref "primary" => Box::new(data),
+        move |_: &_| Ok(data),
+    ];
+
+    assert!(container.has_named::<Data>("primary"));
+    assert!(container.has_ref_named::<Data>("primary"));
+    assert!(!container.has_named::<Data>("replica"));
+    assert!(!container.has_ref::<Data>());
+
+    assert!(container.remove_named::<Data>("primary"));
+    assert!(!container.remove_named::<Data>("primary"));
+    assert!(container.remove_ref_named::<Data>("primary"));
+    assert!(!container.has_named::<Data>("primary"));
+    assert!(!container.has_ref_named::<Data>("primary"));
+    assert!(container.has::<Data>());
+}
+
+#[rstest]
+fn test_replaced_and_removed_providers_are_dropped(data: Data) {
+    let shared = Arc::new(data);
+    let mut container = container![Arc::clone(&shared)];
+
+    container.install(Arc::clone(&shared));
+
+    assert_eq!(2, Arc::strong_count(&shared));
+
+    container.remove::<Arc<Data>>();
+
+    assert_eq!(1, Arc::strong_count(&shared));
+}
+
+#[rstest]
+fn test_references_outlive_removed_ref_provider_of_parent(data: Data) {
+    let mut container = container![ref Box::new(data)];
+    let child = container.create_child();
+
+    let reference = get!(&child, &Data).unwrap();
+    container.remove_ref::<Data>();
+
+    assert!(get!(&child, &Data).is_err());
+    assert_eq!(&data, reference);
+}
+
+#[rstest]
+fn test_providers_lists_lifetimes() {
+    let container = container![singleton!(Data), scoped!(DependsOnData)];
+    let mut child = container.create_child();
+    child.install(|_: &Container| Ok(1isize));
+    child.install_into_set(|_: &Container| Ok("users"));
+    child.install_into_set(|_: &Container| Ok("orders"));
+
+    let lifetimes: Vec<_> = child.providers().map(|info| info.lifetime()).collect();
+
+    assert_eq!(
+        vec![
+            Lifetime::Singleton,
+            Lifetime::Scoped,
+            Lifetime::Transient,
+            Lifetime::Transient,
+            Lifetime::Transient
+        ],
+        lifetimes
+    );
+    assert_eq!(5, child.len());
+    assert!(Container::new().is_empty());
+}
+
+#[rstest]
+fn test_providers_skips_shadowed_and_removed_providers() {
+    let container = container![|_: &Container| Ok(1usize), |_: &Container| Ok(1isize)];
+    let mut child = container.create_child();
+    child.install(|_: &Container| Ok(2usize));
+    child.install(|_: &Container| Ok(3usize));
+    child.install(|_: &Container| Ok(2isize));
+    child.remove::<isize>();
+
+    let types: Vec<_> = child.providers().map(|info| info.type_name()).collect();
+
+    assert_eq!(vec!["isize", "usize"], types);
+}
+
+#[rstest]
+fn test_try_install_keeps_installed_provider(data: Data) {
+    let mut container = Container::new();
+    container.install(move |_: &Container| Ok(data));
+
+    let error = container
+        .try_install(|_: &Container| Ok(Data::new(9)))
+        .unwrap_err();
+
+    assert_eq!(InjectErrorKind::DuplicateBinding, error.kind());
+    assert_eq!(type_name::<Data>(), error.type_name());
+    assert_eq!(Ok(data), get!(&container, Data));
+    assert!(container
+        .try_install_named("other", |_: &Container| Ok(Data::new(9)))
+        .is_ok());
+}
+
+#[rstest]
+fn test_try_install_ref_reports_reference_type(data: Data) {
+    let mut container = container![ref Box::new(data)];
+
+    let error = container.try_install_ref(Box::new(data)).unwrap_err();
+
+    assert_eq!(type_name::<&Data>(), error.type_name());
+}
+
+#[rstest]
+fn test_try_install_named_and_async_keep_installed_providers(data: Data) {
+    let mut container = Container::new();
+    container.install_ref_named("primary", Box::new(data));
+    container.install_async(async_fn(|_: &Container| Box::pin(async { Ok(1usize) })));
+
+    let ref_error = container
+        .try_install_ref_named("primary", Box::new(Data::new(9)))
+        .unwrap_err();
+    let async_error = container
+        .try_install_async(async_fn(|_: &Container| Box::pin(async { Ok(2usize) })))
+        .unwrap_err();
+
+    assert_eq!(type_name::<&Data>(), ref_error.type_name());
+    assert_eq!(InjectErrorKind::DuplicateBinding, async_error.kind());
+    assert_eq!(Ok(&data), get!(&container, &Data, named: "primary"));
+    assert_eq!(Ok(1), block_on(get!(async &container, usize)));
+    assert!(container
+        .try_install_async_named(
+            "other",
+            async_fn(|_: &Container| Box::pin(async { Ok(2usize) }))
+        )
+        .is_ok());
+}
+
+#[rstest]
+fn test_strict_container_allows_replacing_named_and_async_providers(data: Data) {
+    let mut container = Container::strict();
+    container.install_named("primary", |_: &Container| Ok(Data::new(1)));
+    container.install_ref_named("replica", Box::new(Data::new(1)));
+    container.install_async(async_fn(|_: &Container| Box::pin(async { Ok(1usize) })));
+    container.install_async_named(
+        "port",
+        async_fn(|_: &Container| Box::pin(async { Ok(1usize) })),
+    );
+
+    container.replace_named("primary", move |_: &Container| Ok(data));
+    container.replace_ref_named("replica", Box::new(data));
+    container.replace_async(async_fn(|_: &Container| Box::pin(async { Ok(2usize) })));
+    container.replace_async_named(
+        "port",
+        async_fn(|_: &Container| Box::pin(async { Ok(3usize) })),
+    );
+
+    assert_eq!(Ok(data), get!(&container, Data, named: "primary"));
+    assert_eq!(Ok(&data), get!(&container, &Data, named: "replica"));
+    assert_eq!(Ok(2), block_on(get!(async &container, usize)));
+    assert_eq!(
+        Ok(3),
+        block_on(get!(async &container, usize, named: "port"))
+    );
+}
+
+#[rstest]
+#[should_panic(expected = "is already installed")]
+fn test_strict_container_panics_on_duplicate_install() {
+    let mut container = Container::strict();
+    container.install_module(|binder: &mut Container| binder.install(|_: &Container| Ok(1usize)));
+    container.install_module(|binder: &mut Container| binder.install(|_: &Container| Ok(2usize)));
+}
+
+#[rstest]
+fn test_strict_container_allows_replacing_and_shadowing() {
+    let mut container = Container::strict();
+    container.install(|_: &Container| Ok(1usize));
+    container.install_into_set(|_: &Container| Ok("users"));
+    container.install_into_set(|_: &Container| Ok("orders"));
+    container.replace(|_: &Container| Ok(2usize));
+    let mut child = container.create_child();
+    child.install(|_: &Container| Ok(3usize));
+
+    assert!(child.is_strict());
+    assert_eq!(Ok(2), get!(&container, usize));
+    assert_eq!(Ok(3), get!(&child, usize));
+}
+
+#[rstest]
+fn test_strict_container_allows_overrides() {
+    let modules = Modules::new()
+        .with(|binder: &mut Container| binder.install(|_: &Container| Ok(1usize)))
+        .override_with(|binder: &mut Container| binder.install(|_: &Container| Ok(2usize)));
+    let mut container = Container::strict();
+    container.install_module(modules);
+
+    let overridden = container.with_overrides(|container| {
+        container.install(|_: &Container| Ok(3usize));
+        container.install(|_: &Container| Ok(4usize));
+    });
+
+    assert!(overridden.is_strict());
+    assert_eq!(Ok(4), get!(&overridden, usize));
+    assert!(container.is_strict());
+    assert_eq!(Ok(2), get!(&container, usize));
+}
+
+#[rstest]
+fn test_decorators_compose_in_installation_order() {
+    let mut container = container![|_: &Container| Ok(String::from("repo"))];
+    container.decorate(|inner: String, _: &Container| Ok(format!("cached({})", inner)));
+    container.decorate(|inner: String, _: &Container| Ok(format!("timed({})", inner)));
+
+    assert_eq!(Ok("timed(cached(repo))".into()), get!(&container, String));
+    assert_eq!(
+        Ok(Some("timed(cached(repo))".into())),
+        container.try_get::<String>()
+    );
+}
+
+#[rstest]
+fn test_decorators_of_parent_are_applied_first() {
+    let mut container = container![|_: &Container| Ok(1usize), |_: &Container| Ok(10isize)];
+    container.decorate(|inner: usize, _: &Container| Ok(inner + 1));
+    let mut child = container.create_child();
+    child.decorate(|inner: usize, container: &Container| {
+        Ok(inner * container.get::<isize>()? as usize)
+    });
+
+    assert_eq!(Ok(20), get!(&child, usize));
+    assert_eq!(Ok(2), get!(&container, usize));
+}
+
+#[rstest]
+fn test_decorators_apply_to_named_providers_and_singletons() {
+    let mut container = container![
+        singleton!(Data),
+        "port" => |_: &Container| Ok(80usize),
+    ];
+    let decorated = Arc::new(AtomicUsize::new(0));
+    container.decorate({
+        let decorated = Arc::clone(&decorated);
+        move |inner: Arc<Data>, _: &Container| {
+            decorated.fetch_add(1, Ordering::SeqCst);
+            Ok(inner)
+        }
+    });
+    container.decorate(|inner: usize, _: &Container| Ok(inner + 8000));
+
+    get!(&container, Arc<Data>).unwrap();
+    get!(&container, Arc<Data>).unwrap();
+
+    assert_eq!(2, decorated.load(Ordering::SeqCst));
+    assert_eq!(Ok(8080), get!(&container, usize, named: "port"));
+}
+
+#[rstest]
+fn test_failing_decorator_fails_resolution() {
+    let mut container = container![|_: &Container| Ok(1usize)];
+    container.decorate(|_: usize, _: &Container| Err(InjectError::provider_failed("closed")));
+
+    let error = get!(&container, usize).unwrap_err();
+
+    assert_eq!(InjectErrorKind::ProviderFailed, error.kind());
+    assert_eq!("usize", error.type_name());
+}
+
+#[rstest]
+fn test_decorators_are_listed_and_overridable() {
+    let mut container = container![|_: &Container| Ok(1usize)];
+    container.decorate(|inner: usize, _: &Container| Ok(inner + 1));
+
+    let overridden = container.with_overrides(|container| {
+        container.decorate(|inner: usize, _: &Container| Ok(inner * 10))
+    });
+
+    assert_eq!(Ok(20), get!(&overridden, usize));
+
+    let kinds: Vec<_> = container.providers().map(|info| info.kind()).collect();
+
+    assert_eq!(Ok(2), get!(&container, usize));
+    assert_eq!(vec![ProviderKind::Value, ProviderKind::Decorator], kinds);
+}
@@ -1,4 +1,11 @@
-use ::inject::{container, get, singleton, Provider};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use ::inject::graph::Dependency;
+use ::inject::{
+    call, container, get, inject, scoped, singleton, Container, Factory, Inject, InjectError,
+    InjectErrorKind, Lazy, Provider,
+};
 
 mod fixtures;
 
@@ -22,7 +29,9 @@ fn test_construct_with_dependency_using_inject(depends_on_data: DependsOnData) {
 }
 
 #[rstest]
-fn test_construct_with_dependency_using_provider(data_provider: impl Provider + 'static) {
+fn test_construct_with_dependency_using_provider(
+    data_provider: impl Provider + Send + Sync + 'static,
+) {
     let expected_data = DependsOnData::new(Data::new(2), 0);
     let container = container![data_provider];
 
@@ -43,7 +52,7 @@ fn test_construct_with_generic_dependency(generic_data: GenericData<DependsOnDat
 #[rstest]
 fn test_construct_with_arc_dependency(
     arc_data: ArcData,
-    data_arc_provider: impl Provider + 'static,
+    data_arc_provider: impl Provider + Send + Sync + 'static,
 ) {
     let container = container![data_arc_provider];
 
@@ -60,3 +69,440 @@ fn test_install_singleton_returns_same_instance() {
     let injected_data_2 = get!(&container, std::sync::Arc<Data>).unwrap();
     assert_eq!(injected_data_1, injected_data_2)
 }
+
+#[rstest]
+fn test_construct_with_named_dependencies() {
+    let container = container![
+        "primary" => |_: &_| Ok(Data::new(1)),
+        "replica" => |_: &_| Ok(Data::new(2)),
+    ];
+
+    let injected_struct = get!(&container, Replicated).unwrap();
+
+    assert_eq!(injected_struct, Replicated::new(Data::new(1), Data::new(2)));
+}
+
+#[rstest]
+fn test_construct_with_set_dependency(data: Data) {
+    let mut container = container![];
+    container.install_into_set(move |_: &_| Ok(data));
+    container.install_into_set(|_: &_| Ok(Data::new(2)));
+
+    let injected_struct = get!(&container, Collected).unwrap();
+
+    assert_eq!(injected_struct.data, vec![data, Data::new(2)]);
+}
+
+#[rstest]
+fn test_install_scoped_returns_same_instance_within_scope() {
+    let container = container![scoped!(Data)];
+
+    let scope = container.enter_scope();
+    let other_scope = container.enter_scope();
+
+    let injected_data_1 = get!(&scope, std::sync::Arc<Data>).unwrap();
+    let injected_data_2 = get!(&scope, std::sync::Arc<Data>).unwrap();
+    let injected_data_3 = get!(&other_scope, std::sync::Arc<Data>).unwrap();
+
+    assert!(std::sync::Arc::ptr_eq(&injected_data_1, &injected_data_2));
+    assert!(!std::sync::Arc::ptr_eq(&injected_data_1, &injected_data_3));
+}
+
+#[rstest]
+fn test_scoped_instance_is_dropped_with_scope() {
+    let container = container![scoped!(Data)];
+
+    let scope = container.enter_scope();
+    let injected_data = std::sync::Arc::downgrade(&get!(&scope, std::sync::Arc<Data>).unwrap());
+    assert!(injected_data.upgrade().is_some());
+
+    drop(scope);
+    assert!(injected_data.upgrade().is_none());
+}
+
+#[rstest]
+fn test_scoped_outside_scope_is_missing_scope() {
+    let container = container![scoped!(Data)];
+
+    assert_eq!(
+        get!(&container, std::sync::Arc<Data>).map_err(|err| err.kind()),
+        Err(InjectErrorKind::MissingScope)
+    );
+}
+
+#[rstest]
+fn test_scoped_resolved_from_child_of_scope_shares_instance() {
+    let container = container![scoped!(Data)];
+
+    let scope = container.enter_scope();
+    let child = scope.create_child();
+
+    let injected_data_1 = get!(&scope, std::sync::Arc<Data>).unwrap();
+    let injected_data_2 = get!(&child, std::sync::Arc<Data>).unwrap();
+
+    assert!(std::sync::Arc::ptr_eq(&injected_data_1, &injected_data_2));
+}
+
+#[rstest]
+fn test_construct_derived(derived_data: DerivedData) {
+    let container = container!["primary" => |_: &_| Ok(Data::new(2))];
+
+    let injected_struct = get!(&container, DerivedData).unwrap();
+
+    assert_eq!(injected_struct, derived_data);
+}
+
+#[derive(Debug, Clone, PartialEq, Inject)]
+struct DerivedTuple(Data, #[inject(default)] isize);
+
+#[rstest]
+fn test_construct_derived_tuple() {
+    let container = container![|_: &_| Ok(3isize)];
+
+    let injected_struct = get!(&container, DerivedTuple).unwrap();
+
+    assert_eq!(injected_struct, DerivedTuple(Data::new(3), 3));
+}
+
+#[derive(Debug, Clone, PartialEq, Inject)]
+struct DerivedHolder<R> {
+    repo: R,
+    data: Data,
+}
+
+#[rstest]
+fn test_construct_derived_generic_struct() {
+    let container = container![|_: &_| Ok(Postgres)];
+
+    let injected_struct = get!(&container, DerivedHolder<Postgres>).unwrap();
+
+    assert_eq!(
+        injected_struct,
+        DerivedHolder {
+            repo: Postgres,
+            data: Data::new(1)
+        }
+    );
+    assert_eq!(
+        get!(&Container::new(), DerivedHolder<Postgres>).map_err(|err| err.kind()),
+        Err(InjectErrorKind::MissingProvider)
+    );
+}
+
+#[derive(Debug)]
+struct DependsOnLazy {
+    data: Lazy<Data>,
+}
+
+impl DependsOnLazy {
+    #[inject]
+    fn new(data: Lazy<Data>) -> Self {
+        Self { data }
+    }
+}
+
+#[rstest]
+fn test_construct_with_lazy_dependency() {
+    let resolved = Arc::new(AtomicUsize::new(0));
+    let container = {
+        let resolved = Arc::clone(&resolved);
+        container![move |_: &_| {
+            resolved.fetch_add(1, Ordering::SeqCst);
+            Ok(Data::new(2))
+        }]
+    };
+
+    let injected_struct = get!(&container, DependsOnLazy).unwrap();
+
+    assert_eq!(0, resolved.load(Ordering::SeqCst));
+    assert_eq!(Data::new(2), *injected_struct.data);
+    assert_eq!(Ok(&Data::new(2)), injected_struct.data.get());
+    assert_eq!(1, resolved.load(Ordering::SeqCst));
+}
+
+#[rstest]
+fn test_lazy_dependency_failure_is_returned_on_use() {
+    let container = container![];
+
+    let lazy = get!(&container, Lazy<Data>, create: false).unwrap();
+
+    assert_eq!(
+        InjectErrorKind::MissingProvider,
+        lazy.get().unwrap_err().kind()
+    );
+}
+
+struct DependsOnFactory {
+    factory: Factory<Data>,
+    boxed: Box<dyn Fn() -> Result<Data, InjectError>>,
+}
+
+impl DependsOnFactory {
+    #[inject]
+    fn new(factory: Factory<Data>, boxed: Box<dyn Fn() -> Result<Data, InjectError>>) -> Self {
+        Self { factory, boxed }
+    }
+}
+
+#[rstest]
+fn test_construct_with_factory_dependency() {
+    let next = Arc::new(AtomicUsize::new(0));
+    let container =
+        container![move |_: &_| Ok(Data::new(next.fetch_add(1, Ordering::SeqCst) as isize))];
+
+    let injected_struct = get!(&container, DependsOnFactory).unwrap();
+
+    assert_eq!(Ok(Data::new(0)), injected_struct.factory.create());
+    assert_eq!(Ok(Data::new(1)), injected_struct.factory.create());
+    assert_eq!(Ok(Data::new(2)), (injected_struct.boxed)());
+}
+
+#[rstest]
+fn test_lazy_dependency_resolves_within_scope() {
+    let container = container![scoped!(Data)];
+    let scope = container.enter_scope();
+
+    let lazy = get!(&scope, Lazy<std::sync::Arc<Data>>).unwrap();
+    let scoped = get!(&scope, std::sync::Arc<Data>).unwrap();
+
+    assert!(std::sync::Arc::ptr_eq(&scoped, &*lazy));
+}
+
+#[rstest]
+fn test_construct_with_missing_optional_dependencies() {
+    let container = container![];
+
+    let injected_struct = get!(&container, DependsOnOptional).unwrap();
+
+    assert_eq!(
+        DependsOnOptional {
+            data: Some(Data::new(1)),
+            b: None
+        },
+        injected_struct
+    );
+}
+
+#[rstest]
+fn test_optional_is_none_when_creation_lacks_a_provider() {
+    let container = container![];
+
+    assert_eq!(Ok(Some(Data::new(1))), get!(&container, Option<Data>));
+    assert_eq!(
+        Ok(true),
+        get!(&container, Option<DependsOnDyn>).map(|it| it.is_none())
+    );
+    assert_eq!(Ok(None), get!(&container, Option<Data>, create: false));
+}
+
+#[rstest]
+fn test_construct_with_optional_dependencies(data_provider: impl Provider + Send + Sync + 'static) {
+    let container = container![data_provider, "b" => |_: &_| Ok(3isize)];
+
+    let injected_struct = get!(&container, DependsOnOptional).unwrap();
+
+    assert_eq!(
+        DependsOnOptional {
+            data: Some(Data::new(2)),
+            b: Some(3)
+        },
+        injected_struct
+    );
+}
+
+#[rstest]
+fn test_optional_dependency_propagates_provider_errors() {
+    let container =
+        container![|container: &::inject::Container| Ok(Data::new(container.get::<isize>()?))];
+
+    let error = get!(&container, DependsOnOptional).unwrap_err();
+
+    assert_eq!(InjectErrorKind::MissingProvider, error.kind());
+    assert!(error.type_name().ends_with("isize"));
+}
+
+#[rstest]
+fn test_call_with_optional_reference() {
+    let container = container![];
+    assert_eq!(Ok(None), call!(&container, optional_ref));
+
+    let container = container![ref Box::new(Data::new(4))];
+    assert_eq!(Ok(Some(4)), call!(&container, optional_ref));
+}
+
+struct Service {
+    repo: Box<dyn Repo>,
+    data: Data,
+}
+
+impl Service {
+    #[inject]
+    fn new<R: Repo + 'static>(repo: R, data: Data) -> Self {
+        Self {
+            repo: Box::new(repo),
+            data,
+        }
+    }
+}
+
+#[rstest]
+fn test_construct_with_generic_constructor(data: Data) {
+    let container = container![|_: &_| Ok(Sqlite)];
+
+    let service = Service::inject::<Sqlite>(&container).unwrap();
+
+    assert_eq!("sqlite", service.repo.name());
+    assert_eq!(data, service.data);
+}
+
+#[rstest]
+fn test_generic_constructor_only_resolves_type_parameters_using_providers() {
+    let container = container![];
+
+    let error = Service::inject::<Postgres>(&container).err().unwrap();
+
+    assert_eq!(InjectErrorKind::MissingProvider, error.kind());
+}
+
+#[rstest]
+fn test_construct_with_kwargs(data: Data) {
+    let container = container![|_: &_| Ok(2isize)];
+
+    let depends_on_data = get!(&container, DependsOnData, kwargs = { b: 7 }).unwrap();
+
+    assert_eq!(DependsOnData::new(Data::new(2), 7), depends_on_data);
+
+    let depends_on_data = get!(&container, DependsOnData, kwargs = { data }).unwrap();
+
+    assert_eq!(DependsOnData::new(data, 2), depends_on_data);
+}
+
+#[rstest]
+fn test_construct_with_trait_object_kwarg(data: Data) {
+    let container = container![];
+    let test_trait: Arc<dyn TestTrait> = Arc::new(data);
+
+    let depends_on_dyn = get!(&container, DependsOnDyn, kwargs = { test_trait }).unwrap();
+
+    assert_eq!("Hello", depends_on_dyn.test_trait.hello());
+}
+
+#[rstest]
+fn test_construct_with_kwargs_bypasses_providers(depends_on_data: DependsOnData) {
+    let container = container![move |_: &_| Ok(depends_on_data)];
+
+    let constructed = get!(&container, DependsOnData, kwargs = { b: 3 }).unwrap();
+
+    assert_eq!(3, constructed.b);
+    assert_ne!(depends_on_data, constructed);
+}
+
+#[rstest]
+fn test_construct_generic_constructor_with_kwargs(data: Data) {
+    let container = container![];
+
+    let service = get!(&container, Service, kwargs = { repo: Postgres }).unwrap();
+
+    assert_eq!("postgres", service.repo.name());
+    assert_eq!(data, service.data);
+}
+
+#[rstest]
+fn test_inject_dependencies_of_constructor() {
+    assert_eq!(
+        vec![
+            Dependency::of::<Data>(Some("primary")),
+            Dependency::of::<Data>(Some("replica"))
+        ],
+        Replicated::inject_dependencies()
+    );
+    assert_eq!(
+        vec![Dependency::new("R", None), Dependency::of::<Data>(None)],
+        Service::inject_dependencies()
+    );
+}
+
+#[rstest]
+fn test_post_construct_is_called(data: Data) {
+    let container = container![];
+
+    let started = get!(&container, Started).unwrap();
+
+    assert!(started.started);
+    assert_eq!(data, started.data);
+}
+
+#[rstest]
+fn test_post_construct_is_called_with_kwargs() {
+    let container = container![];
+
+    let started = get!(&container, Started, kwargs = { data: Data::new(3) }).unwrap();
+
+    assert!(started.started);
+    assert_eq!(Data::new(3), started.data);
+}
+
+#[rstest]
+fn test_failing_post_construct_fails_injection() {
+    let container = container![];
+
+    let error = get!(&container, FailsToStart).err().unwrap();
+
+    assert_eq!(InjectErrorKind::ProviderFailed, error.kind());
+    assert_eq!(
+        "port 1 in use",
+        std::error::Error::source(&error).unwrap().to_string()
+    );
+}
+
+#[rstest]
+fn test_shutdown_disposes_singletons_in_reverse_creation_order() {
+    let log = Arc::new(DisposalLog::default());
+    let container = container![
+        singleton!(Repository),
+        singleton!(Pool),
+        singleton!(Data),
+        Arc::clone(&log),
+    ];
+
+    get!(&container, Arc<Repository>).unwrap();
+    get!(&container, Arc<Data>).unwrap();
+    container.shutdown();
+    container.shutdown();
+
+    assert_eq!(vec!["repository", "pool"], *log.0.lock().unwrap());
+}
+
+#[rstest]
+fn test_singletons_create_new_instances_after_shutdown() {
+    let log = Arc::new(DisposalLog::default());
+    let container = container![singleton!(Pool), singleton!(Data), Arc::clone(&log)];
+
+    let pool = get!(&container, Arc<Pool>).unwrap();
+    let data = get!(&container, Arc<Data>).unwrap();
+    container.shutdown();
+
+    assert!(!Arc::ptr_eq(&pool, &get!(&container, Arc<Pool>).unwrap()));
+    assert!(!Arc::ptr_eq(&data, &get!(&container, Arc<Data>).unwrap()));
+
+    container.shutdown();
+
+    assert_eq!(vec!["pool", "pool"], *log.0.lock().unwrap());
+}
+
+#[rstest]
+fn test_shutdown_leaves_singletons_of_parent() {
+    let log = Arc::new(DisposalLog::default());
+    let container = container![singleton!(Pool), Arc::clone(&log)];
+    let mut child = container.create_child();
+    child.install(singleton!(Repository));
+
+    get!(&child, Arc<Repository>).unwrap();
+    child.shutdown();
+
+    assert_eq!(vec!["repository"], *log.0.lock().unwrap());
+
+    container.shutdown();
+
+    assert_eq!(vec!["repository", "pool"], *log.0.lock().unwrap());
+}
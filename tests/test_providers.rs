@@ -59,3 +59,13 @@ fn test_default_provider() {
     let reference: &FakeImpl = get!(&container, &FakeImpl).unwrap();
     assert_eq!(&boxed_ref, reference);
 }
+
+#[test]
+fn test_custom_ref_providers() {
+    let fake_impl = FakeImpl { val: 2 };
+    let container = container![ref RefProvide { fake_impl }];
+    assert_eq!(Ok(&fake_impl), get!(&container, &FakeImpl));
+
+    let container = container![ref BoxProvider { a: Box::new(fake_impl) }];
+    assert_eq!(Ok(&fake_impl), get!(&container, &FakeImpl));
+}